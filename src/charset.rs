@@ -0,0 +1,376 @@
+use crate::entity::CharacterSet;
+use crate::unicode::UnicodeProperty;
+use std::{iter::FromIterator, ops::RangeInclusive, str::FromStr};
+use thiserror::Error;
+
+// the max unicode code point
+const MAX_CODE_POINT: u32 = 0x10ffff;
+// the surrogates are not characters
+const SURROGATES: (u32, u32) = (0xd800, 0xdfff);
+
+/// CharSetParseError: the errors of parsing the class string into a `CharSet`, the index is the byte index in the class string.
+#[non_exhaustive]
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CharSetParseError {
+  /// The class string doesn't start with '[' or end with an unescaped ']'.
+  #[error("The class string should be wrapped by '[' and ']'.")]
+  MissingBracket,
+  /// The escape sequence is unknown or incomplete.
+  #[error("Invalid escape sequence at {0}.")]
+  InvalidEscape(usize),
+  /// The escaped code point is a surrogate or outside the unicode range.
+  #[error("Invalid code point at {0}.")]
+  InvalidCodePoint(usize),
+  /// The start of the range is greater than the end.
+  #[error("Invalid range at {0}.")]
+  InvalidRange(usize),
+}
+
+/// CharSet: a set of characters built from the code point ranges and the characters, which can be used by `CharacterSet::Custom`.
+///
+/// The ascii characters are checked by a bitmap, other characters are searched in the sorted ranges.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::charset::CharSet;
+/// use htmlentity::entity::CharacterSet;
+///
+/// let mut set = CharSet::from(&CharacterSet::SpecialChars);
+/// set.insert('`');
+/// set.insert_range('\u{80}'..='\u{ffff}');
+/// assert!(set.contains(&'<') && set.contains(&'`') && set.contains(&'\u{4e16}'));
+/// assert!(!set.contains(&'a') && !set.contains(&'\u{1f600}'));
+/// // parse from a class string
+/// let parsed: CharSet = "[<>&\"'`\\u0080-\\uFFFF]".parse().unwrap();
+/// assert_eq!(parsed, set);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CharSet {
+  // the bitmap of the ascii characters
+  ascii: u128,
+  // the sorted, disjoint and non-adjacent code point ranges of all the characters
+  ranges: Vec<(u32, u32)>,
+}
+
+impl CharSet {
+  /// Create an empty character set.
+  pub fn new() -> Self {
+    Default::default()
+  }
+  /// Parse a regex-like class string into a character set, e.g. `[<>&\u{80}-\u{10FFFF}]`.
+  ///
+  /// The class supports the ranges `a-z`, the negation `[^...]`, and the escapes `\uXXXX`, `\u{X..}`, `\xXX`,
+  /// `\t`, `\n`, `\r`, `\f`, `\v`, `\0` and the escaped ascii punctuations such as `\]`, `\-` and `\\`.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::charset::{CharSet, CharSetParseError};
+  ///
+  /// let set = CharSet::parse(r"[a-c\-\u{1F600}]").unwrap();
+  /// assert!(set.contains(&'b') && set.contains(&'-') && set.contains(&'\u{1f600}'));
+  /// let set = CharSet::parse("[^a-z]").unwrap();
+  /// assert!(!set.contains(&'b') && set.contains(&'B'));
+  /// assert_eq!(CharSet::parse("[z-a]"), Err(CharSetParseError::InvalidRange(1)));
+  /// assert_eq!(CharSet::parse(r"[\q]"), Err(CharSetParseError::InvalidEscape(1)));
+  /// ```
+  pub fn parse(class: &str) -> Result<Self, CharSetParseError> {
+    ClassParser::new(class).parse()
+  }
+  /// Add a character into the set.
+  pub fn insert(&mut self, ch: char) {
+    self.insert_range(ch..=ch);
+  }
+  /// Add the characters in the range into the set, the surrogates in the range are ignored.
+  pub fn insert_range(&mut self, range: RangeInclusive<char>) {
+    let (start, end) = (*range.start() as u32, *range.end() as u32);
+    if start <= end {
+      self.ranges.push((start, end));
+      self.normalize();
+    }
+  }
+  /// Check if the character is in the set.
+  pub fn contains(&self, ch: &char) -> bool {
+    let code = *ch as u32;
+    if code < 128 {
+      return (self.ascii >> code) & 1 == 1;
+    }
+    let index = self.ranges.partition_point(|&(_, end)| end < code);
+    self
+      .ranges
+      .get(index)
+      .is_some_and(|&(start, _)| start <= code)
+  }
+  /// Check if the set has no character.
+  pub fn is_empty(&self) -> bool {
+    self.ranges.is_empty()
+  }
+  /// Iterate the sorted and disjoint character ranges of the set.
+  pub fn ranges(&self) -> impl Iterator<Item = RangeInclusive<char>> + '_ {
+    self.ranges.iter().map(|&(start, end)| {
+      // the surrogates are always excluded
+      char::from_u32(start).unwrap()..=char::from_u32(end).unwrap()
+    })
+  }
+  /// The characters in either set.
+  pub fn union(&self, other: &CharSet) -> CharSet {
+    let mut ranges = self.ranges.clone();
+    ranges.extend_from_slice(&other.ranges);
+    Self::from_code_ranges(ranges)
+  }
+  /// The characters in both sets.
+  pub fn intersection(&self, other: &CharSet) -> CharSet {
+    let mut ranges = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < self.ranges.len() && j < other.ranges.len() {
+      let (start, end) = self.ranges[i];
+      let (other_start, other_end) = other.ranges[j];
+      if start.max(other_start) <= end.min(other_end) {
+        ranges.push((start.max(other_start), end.min(other_end)));
+      }
+      // move the range ends first
+      if end < other_end {
+        i += 1;
+      } else {
+        j += 1;
+      }
+    }
+    Self::from_code_ranges(ranges)
+  }
+  /// The characters in this set but not in the other set.
+  pub fn difference(&self, other: &CharSet) -> CharSet {
+    self.intersection(&other.complement())
+  }
+  /// The characters not in the set.
+  pub fn complement(&self) -> CharSet {
+    let mut ranges = vec![];
+    let mut next = 0;
+    for &(start, end) in &self.ranges {
+      if start > next {
+        ranges.push((next, start - 1));
+      }
+      next = end + 1;
+    }
+    if next <= MAX_CODE_POINT {
+      ranges.push((next, MAX_CODE_POINT));
+    }
+    Self::from_code_ranges(ranges)
+  }
+  // create the set from the unsorted code point ranges
+  fn from_code_ranges(ranges: Vec<(u32, u32)>) -> Self {
+    let mut set = CharSet { ascii: 0, ranges };
+    set.normalize();
+    set
+  }
+  // sort and merge the ranges, remove the surrogates, then rebuild the ascii bitmap
+  fn normalize(&mut self) {
+    self.ranges.sort_unstable();
+    let mut ranges: Vec<(u32, u32)> = Vec::with_capacity(self.ranges.len() + 1);
+    let (surrogate_start, surrogate_end) = SURROGATES;
+    for &(start, end) in &self.ranges {
+      // split the range by the surrogates
+      let parts = [
+        (start, end.min(surrogate_start - 1)),
+        (start.max(surrogate_end + 1), end),
+      ];
+      for &(start, end) in parts.iter() {
+        if start > end {
+          continue;
+        }
+        match ranges.last_mut() {
+          Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+          _ => ranges.push((start, end)),
+        }
+      }
+    }
+    self.ranges = ranges;
+    self.ascii = 0;
+    for &(start, end) in &self.ranges {
+      if start >= 128 {
+        break;
+      }
+      for code in start..=end.min(127) {
+        self.ascii |= 1 << code;
+      }
+    }
+  }
+}
+
+impl FromStr for CharSet {
+  type Err = CharSetParseError;
+  fn from_str(class: &str) -> Result<Self, Self::Err> {
+    CharSet::parse(class)
+  }
+}
+
+impl FromIterator<char> for CharSet {
+  fn from_iter<T: IntoIterator<Item = char>>(iter: T) -> Self {
+    let ranges = iter.into_iter().map(|ch| (ch as u32, ch as u32)).collect();
+    Self::from_code_ranges(ranges)
+  }
+}
+
+impl From<RangeInclusive<char>> for CharSet {
+  fn from(range: RangeInclusive<char>) -> Self {
+    let mut set = CharSet::new();
+    set.insert_range(range);
+    set
+  }
+}
+
+#[allow(deprecated)]
+impl From<&CharacterSet> for CharSet {
+  fn from(charset: &CharacterSet) -> Self {
+    use CharacterSet::*;
+    match charset {
+      All => CharSet::from('\0'..=char::MAX),
+      NonAscii => CharSet::from('\u{80}'..=char::MAX),
+      NonLatin1 | NonASCII => CharSet::from('\u{100}'..=char::MAX),
+      Html => "<>&".chars().collect(),
+      SpecialChars => "<>&'\"".chars().collect(),
+      HtmlAndNonAscii => CharSet::from(&NonAscii).union(&CharSet::from(&Html)),
+      SpecialCharsAndNonAscii => CharSet::from(&NonAscii).union(&CharSet::from(&SpecialChars)),
+      HtmlAndNonLatin1 | HtmlAndNonASCII => CharSet::from(&NonLatin1).union(&CharSet::from(&Html)),
+      SpecialCharsAndNonLatin1 | SpecialCharsAndNonASCII => {
+        CharSet::from(&NonLatin1).union(&CharSet::from(&SpecialChars))
+      }
+      Custom(set) => set.clone(),
+      CharacterSet::UnicodeProperty(property) => CharSet::from(property),
+      NotRepresentableIn(encoding) => encoding
+        .representable_chars()
+        .collect::<CharSet>()
+        .complement(),
+      // all the invisible characters are between U+061C and U+FEFF
+      BidiControls | ZeroWidth | Invisible => ('\u{61c}'..='\u{feff}')
+        .filter(|ch| charset.contains(ch))
+        .collect(),
+      HtmlAndInvisible => CharSet::from(&Invisible).union(&CharSet::from(&Html)),
+      SpecialCharsAndInvisible => CharSet::from(&Invisible).union(&CharSet::from(&SpecialChars)),
+      Markdown => ('\0'..='\x7f').filter(|ch| charset.contains(ch)).collect(),
+    }
+  }
+}
+
+impl From<&UnicodeProperty> for CharSet {
+  fn from(property: &UnicodeProperty) -> Self {
+    Self::from_code_ranges(property.code_ranges().collect())
+  }
+}
+
+impl From<CharSet> for CharacterSet {
+  fn from(set: CharSet) -> Self {
+    CharacterSet::Custom(set)
+  }
+}
+
+// the parser of the regex-like class string
+struct ClassParser<'a> {
+  class: &'a str,
+  index: usize,
+}
+
+impl<'a> ClassParser<'a> {
+  fn new(class: &'a str) -> Self {
+    ClassParser { class, index: 0 }
+  }
+  fn peek(&self) -> Option<char> {
+    self.class[self.index..].chars().next()
+  }
+  fn next(&mut self) -> Option<char> {
+    let ch = self.peek()?;
+    self.index += ch.len_utf8();
+    Some(ch)
+  }
+  fn parse(mut self) -> Result<CharSet, CharSetParseError> {
+    if self.next() != Some('[') {
+      return Err(CharSetParseError::MissingBracket);
+    }
+    let negated = self.peek() == Some('^');
+    if negated {
+      self.next();
+    }
+    let mut ranges = vec![];
+    loop {
+      let start_index = self.index;
+      let start = match self.peek() {
+        None => return Err(CharSetParseError::MissingBracket),
+        Some(']') => {
+          self.next();
+          break;
+        }
+        Some(_) => self.parse_char()?,
+      };
+      // the '-' before the ending ']' is a literal
+      let rest = &self.class[self.index..];
+      let end = if rest.starts_with('-') && !rest.starts_with("-]") {
+        self.next();
+        let end = self.parse_char()?;
+        if start > end {
+          return Err(CharSetParseError::InvalidRange(start_index));
+        }
+        end
+      } else {
+        start
+      };
+      ranges.push((start as u32, end as u32));
+    }
+    if self.index != self.class.len() {
+      return Err(CharSetParseError::MissingBracket);
+    }
+    let set = CharSet::from_code_ranges(ranges);
+    if negated {
+      return Ok(set.complement());
+    }
+    Ok(set)
+  }
+  // parse a literal or an escaped character
+  fn parse_char(&mut self) -> Result<char, CharSetParseError> {
+    let index = self.index;
+    let ch = self.next().ok_or(CharSetParseError::MissingBracket)?;
+    if ch != '\\' {
+      return Ok(ch);
+    }
+    let invalid_escape = CharSetParseError::InvalidEscape(index);
+    let code = match self.next().ok_or(invalid_escape)? {
+      'u' if self.peek() == Some('{') => {
+        self.next();
+        let digits = self.take_hex_digits(6);
+        if digits.is_empty() || self.next() != Some('}') {
+          return Err(invalid_escape);
+        }
+        u32::from_str_radix(digits, 16).unwrap()
+      }
+      'u' => self.parse_fixed_hex(4).ok_or(invalid_escape)?,
+      'x' => self.parse_fixed_hex(2).ok_or(invalid_escape)?,
+      't' => 0x09,
+      'n' => 0x0a,
+      'v' => 0x0b,
+      'f' => 0x0c,
+      'r' => 0x0d,
+      '0' => 0,
+      ch if ch.is_ascii_punctuation() => ch as u32,
+      _ => return Err(invalid_escape),
+    };
+    char::from_u32(code).ok_or(CharSetParseError::InvalidCodePoint(index))
+  }
+  // take at most `max_len` hex digits
+  fn take_hex_digits(&mut self, max_len: usize) -> &'a str {
+    let rest = &self.class[self.index..];
+    let len = rest
+      .bytes()
+      .take(max_len)
+      .take_while(|byte| byte.is_ascii_hexdigit())
+      .count();
+    self.index += len;
+    &rest[..len]
+  }
+  // parse exactly `len` hex digits
+  fn parse_fixed_hex(&mut self, len: usize) -> Option<u32> {
+    let digits = self.take_hex_digits(len);
+    if digits.len() != len {
+      return None;
+    }
+    u32::from_str_radix(digits, 16).ok()
+  }
+}
@@ -0,0 +1,124 @@
+use crate::entity::{
+  choose_char_entity, parse_reference, CharacterSet, DecodeOptions, EncodeType, NumericFormat,
+};
+use std::fmt::{self, Display, Write};
+
+/// Escaped: encode the content when it's formatted, no intermediate buffer is built.
+///
+/// The unchanged runs of the content are written as `&str` slices, and the entities are written into the formatter directly.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::display::Escaped;
+/// use htmlentity::entity::{CharacterSet, EncodeType};
+///
+/// let escaped = Escaped::new("<b>世界</b>", EncodeType::NamedOrHex, CharacterSet::HtmlAndNonAscii);
+/// assert_eq!(format!("<p>{}</p>", escaped), "<p>&lt;b&gt;&#x4e16;&#x754c;&lt;/b&gt;</p>");
+/// ```
+pub struct Escaped<'a> {
+  content: &'a str,
+  encode_type: EncodeType,
+  charset: CharacterSet,
+}
+
+impl<'a> Escaped<'a> {
+  /// Create an escaped display of the content, like the `encode` method.
+  pub fn new(content: &'a str, encode_type: EncodeType, charset: CharacterSet) -> Self {
+    Escaped {
+      content,
+      encode_type,
+      charset,
+    }
+  }
+}
+
+impl<'a> Display for Escaped<'a> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let content = self.content;
+    let mut start_index = 0;
+    for (index, ch) in content.char_indices() {
+      let (need_encode, maybe_entity) =
+        self
+          .charset
+          .choose(&ch, &self.encode_type, &NumericFormat::default());
+      if !need_encode {
+        continue;
+      }
+      // write the unchanged run before the character
+      f.write_str(&content[start_index..index])?;
+      start_index = index;
+      // the entity is chosen like the `encode_char` method if the character set doesn't give one
+      if let Some(entity) = maybe_entity.or_else(|| choose_char_entity(ch, &self.encode_type)) {
+        entity.write_to(f)?;
+        start_index = index + ch.len_utf8();
+      }
+    }
+    f.write_str(&content[start_index..])
+  }
+}
+
+/// Escape the special characters `<`, `>`, `&`, `'` and `"` into named entities when it's formatted.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::display::escape;
+///
+/// let user = "<script>alert('x')</script>";
+/// assert_eq!(
+///   format!("<p>{}</p>", escape(user)),
+///   "<p>&lt;script&gt;alert(&apos;x&apos;)&lt;/script&gt;</p>"
+/// );
+/// ```
+pub fn escape(content: &str) -> Escaped<'_> {
+  Escaped::new(content, EncodeType::Named, CharacterSet::SpecialChars)
+}
+
+/// Decoded: decode the html entities in the content when it's formatted, no intermediate buffer is built.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::display::Decoded;
+/// use htmlentity::entity::{DecodeMode, DecodeOptions};
+///
+/// let decoded = Decoded::new("&lt;p&gt;&#x4e16;&copy", DecodeOptions::default());
+/// assert_eq!(decoded.to_string(), "<p>\u{4e16}&copy");
+/// let options = DecodeOptions { mode: DecodeMode::Html5, ..Default::default() };
+/// assert_eq!(Decoded::new("&copy 2023", options).to_string(), "\u{a9} 2023");
+/// ```
+pub struct Decoded<'a> {
+  content: &'a str,
+  options: DecodeOptions,
+}
+
+impl<'a> Decoded<'a> {
+  /// Create a decoded display of the content, like the `decode_with` method.
+  pub fn new(content: &'a str, options: DecodeOptions) -> Self {
+    Decoded { content, options }
+  }
+}
+
+impl<'a> Display for Decoded<'a> {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let content = self.content;
+    let bytes = content.as_bytes();
+    let mut start_index = 0;
+    let mut index = 0;
+    while let Some(pos) = bytes[index..].iter().position(|&byte| byte == b'&') {
+      let amp_index = index + pos;
+      if let Some(reference) = parse_reference(bytes, amp_index, &self.options, None) {
+        f.write_str(&content[start_index..amp_index])?;
+        for &ch in reference.chars.as_slice() {
+          f.write_char(ch)?;
+        }
+        start_index = reference.end_index;
+        index = reference.end_index;
+      } else {
+        index = amp_index + 1;
+      }
+    }
+    f.write_str(&content[start_index..])
+  }
+}
@@ -1,13 +1,23 @@
+use crate::unicode::UnicodeProperty;
 use crate::{
-  data::{ENTITIES, FIRST_LETTER_POSITION, LETTER_ORDERED_ENTITIES},
+  charset::CharSet,
+  data::{EntityPointPair, ENTITIES, HTML4_ENTITIES},
+  legacy::LegacyEncoding,
+  trie::ENTITY_TRIE,
   types::{
-    AnyhowResult, Byte, ByteList, BytesCharEntity, CharListResult, CodeRange, CodeRangeTuple,
-    EncodeFilterReturnData, EntityCharBytes, IterDataItem, StringResult,
+    AnyhowResult, Byte, ByteList, Bytes, CharListResult, Chars, CodeRange, CodeRangeTuple,
+    DecodedChars, EncodeFilterReturnData, EntityCharBytes, IterDataItem, StringResult,
   },
 };
 
 use lazy_static::lazy_static;
-use std::{borrow::Cow, char, cmp::Ordering, collections::HashMap, fmt::Display};
+use std::{
+  borrow::Cow,
+  char,
+  cmp::Ordering,
+  collections::HashMap,
+  fmt::{Display, Write},
+};
 use thiserror::Error;
 
 lazy_static! {
@@ -29,21 +39,65 @@ lazy_static! {
     }
     map
   };
-  // normal name entity
-  static ref NORMAL_NAME_ENTITY_BYTE: BytesCharEntity = {
-    let mut map: BytesCharEntity = HashMap::with_capacity(10);
-    map.insert(b"lt", '<');
-    map.insert(b"LT", '<');
-    map.insert(b"gt", '>');
-    map.insert(b"GT", '>');
-    map.insert(b"amp", '&');
-    map.insert(b"AMP", '&');
-    map.insert(b"quot", '"');
-    map.insert(b"QUOT", '"');
-    map.insert(b"apos", '\'');
-    map.insert(b"nbsp", 0xa0 as char);
-    map
-  };
+}
+
+/// DecodeErrorKind: the parse errors of the character references, mirror the WHATWG parse error codes.
+#[non_exhaustive]
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodeErrorKind {
+  /// The character reference is not terminated by a ';'.
+  #[error("Missing semicolon after the character reference.")]
+  MissingSemicolonAfterCharacterReference,
+  /// An alphanumeric sequence terminated by a ';' doesn't match any named character reference,
+  /// it's also returned by `Entity::decode` for an empty name or a name which is not ascii alphanumeric.
+  #[error("Unable to find the corresponding named character reference.")]
+  UnknownNamedCharacterReference,
+  /// A numeric character reference doesn't contain any digits, e.g. `&#;` or `&#xg;`.
+  #[error("The numeric character reference doesn't contain any digits.")]
+  AbsenceOfDigitsInNumericCharacterReference,
+  /// A numeric character reference references the U+0000 NULL code point.
+  #[error("The numeric character reference is a null character.")]
+  NullCharacterReference,
+  /// A numeric character reference references a code point greater than 0x10FFFF.
+  #[error("The numeric character reference is outside the unicode range.")]
+  CharacterReferenceOutsideUnicodeRange,
+  /// A numeric character reference references a surrogate.
+  #[error("The numeric character reference is a surrogate.")]
+  SurrogateCharacterReference,
+  /// A numeric character reference references a noncharacter.
+  #[error("The numeric character reference is a noncharacter.")]
+  NoncharacterCharacterReference,
+  /// A numeric character reference references a control that is not ASCII whitespace, or a U+000D CARRIAGE RETURN.
+  #[error("The numeric character reference is a control character.")]
+  ControlCharacterReference,
+  /// An '&' which doesn't start a character reference in the `DecodeMode::Xml` mode, it should be encoded as `&amp;`.
+  /// A literal '&' in the html modes is not a parse error.
+  #[error("Unencoded html entity character '&'.")]
+  AmbiguousAmpersand,
+  /// A numeric character reference doesn't reference a `Char` of the xml version in the `DecodeMode::Xml` mode, it's not a WHATWG parse error.
+  #[error("The numeric character reference is not a legal xml character.")]
+  InvalidXmlCharacterReference,
+}
+
+impl DecodeErrorKind {
+  /// The WHATWG parse error code of the error.
+  pub fn code(&self) -> &'static str {
+    use DecodeErrorKind::*;
+    match self {
+      MissingSemicolonAfterCharacterReference => "missing-semicolon-after-character-reference",
+      UnknownNamedCharacterReference => "unknown-named-character-reference",
+      AbsenceOfDigitsInNumericCharacterReference => {
+        "absence-of-digits-in-numeric-character-reference"
+      }
+      NullCharacterReference => "null-character-reference",
+      CharacterReferenceOutsideUnicodeRange => "character-reference-outside-unicode-range",
+      SurrogateCharacterReference => "surrogate-character-reference",
+      NoncharacterCharacterReference => "noncharacter-character-reference",
+      ControlCharacterReference => "control-character-reference",
+      AmbiguousAmpersand => "ambiguous-ampersand",
+      InvalidXmlCharacterReference => "invalid-xml-character-reference",
+    }
+  }
 }
 
 #[derive(Error, Debug)]
@@ -54,11 +108,37 @@ pub enum HtmlEntityError {
   Encode(String),
 }
 
+/// AttributeError: the errors of the `write_attribute` method.
+#[non_exhaustive]
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AttributeError {
+  /// The attribute name is empty, not utf-8, or contains controls, whitespaces, '"', '\'', '>', '/', '=' or noncharacters.
+  #[error("Invalid attribute name.")]
+  InvalidName,
+  /// The attribute value is empty or contains the characters that can't be in an unquoted attribute value.
+  #[error("The attribute value can't be unquoted safely.")]
+  UnsafeUnquotedValue,
+}
+
+/// EncodeError: the errors of the `encode_with_options`, `encode_with_options_to` and `encode_chars_with_options` methods.
+#[non_exhaustive]
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EncodeError {
+  /// The forbidden code point is found by `ForbiddenCodePointPolicy::Error`, the index is the byte index in the utf-8 bytes or the index in the characters.
+  #[error("Forbidden code point {1:?} at {0}.")]
+  ForbiddenCodePoint(usize, char),
+}
+
 #[inline]
-fn char_to_utf8_bytes(ch: char) -> ByteList {
-  let len = ch.len_utf8();
+pub(crate) fn chars_to_utf8_bytes(chars: &[char]) -> ByteList {
+  let len = chars.iter().map(|ch| ch.len_utf8()).sum();
   let mut bytes: ByteList = vec![0; len];
-  ch.encode_utf8(&mut bytes);
+  let mut start_index = 0;
+  for ch in chars {
+    let end_index = start_index + ch.len_utf8();
+    ch.encode_utf8(&mut bytes[start_index..end_index]);
+    start_index = end_index;
+  }
   bytes
 }
 
@@ -75,22 +155,89 @@ fn tr_chars_to_utf8_bytes(chars: &[char]) -> Option<ByteList> {
   Some(bytes)
 }
 
+// the code points which the numeric references in range 0x80 - 0x9f are remapped to, 0 means not remapped
+const C1_REPLACEMENTS: [u32; 32] = [
+  0x20ac, 0, 0x201a, 0x192, 0x201e, 0x2026, 0x2020, 0x2021, 0x2c6, 0x2030, 0x160, 0x2039, 0x152, 0,
+  0x17d, 0, 0, 0x2018, 0x2019, 0x201c, 0x201d, 0x2022, 0x2013, 0x2014, 0x2dc, 0x2122, 0x161,
+  0x203a, 0x153, 0, 0x17e, 0x178,
+];
+
 #[inline]
-fn numbers_to_char(bytes: &[Byte], radix: u32) -> AnyhowResult<char> {
-  if !bytes.is_empty() {
-    // '&#;' '&#x;'
-    let num = std::str::from_utf8(bytes)?;
-    let char_code = i64::from_str_radix(num, radix)?;
-    return std::char::from_u32(char_code as u32).ok_or(
-      HtmlEntityError::Decode(format!(
-        "The html entity number '&{}{};' is not a valid encoded character.",
-        if radix == 16 { "#" } else { "" },
-        num
-      ))
-      .into(),
-    );
+fn is_noncharacter(char_code: u32) -> bool {
+  (0xfdd0..=0xfdef).contains(&char_code) || (char_code & 0xfffe) == 0xfffe
+}
+
+// the C0 controls except the ascii whitespaces, the lone CR, the C1 controls and the noncharacters are forbidden in html documents
+#[inline]
+fn is_forbidden_code_point(ch: char, followed_by_lf: bool) -> bool {
+  match ch {
+    '\t' | '\n' | '\x0c' => false,
+    '\r' => !followed_by_lf,
+    '\0'..='\x1f' | '\x7f'..='\u{9f}' => true,
+    _ => is_noncharacter(ch as u32),
+  }
+}
+
+// the numeric reference with more significant digits is kept as is in the strict mode,
+// so a stream decoder needn't buffer all the digits to decide the output
+pub(crate) const MAX_STRICT_SIGNIFICANT_DIGITS: usize = 32;
+
+// parse the digits of a numeric reference, the overflowed number is kept as 0x110000
+#[inline]
+fn parse_char_code(bytes: &[Byte], radix: u32) -> u32 {
+  let mut char_code: u32 = 0;
+  for byte in bytes {
+    // the digits have been checked
+    let num = (*byte as char).to_digit(radix).unwrap_or(0);
+    // keep the overflowed number out of the unicode range
+    char_code = (char_code * radix + num).min(0x110000);
+  }
+  char_code
+}
+
+// parse the digits of a numeric reference by the html5 numeric character reference end state,
+// the digits can be any length, return the character and the parse error if has.
+#[inline]
+pub(crate) fn numbers_to_char(bytes: &[Byte], radix: u32) -> (char, Option<DecodeErrorKind>) {
+  let char_code = parse_char_code(bytes, radix);
+  match char_code {
+    0 => (
+      char::REPLACEMENT_CHARACTER,
+      Some(DecodeErrorKind::NullCharacterReference),
+    ),
+    0xd800..=0xdfff => (
+      char::REPLACEMENT_CHARACTER,
+      Some(DecodeErrorKind::SurrogateCharacterReference),
+    ),
+    0x110000.. => (
+      char::REPLACEMENT_CHARACTER,
+      Some(DecodeErrorKind::CharacterReferenceOutsideUnicodeRange),
+    ),
+    _ => {
+      let error = if is_noncharacter(char_code) {
+        Some(DecodeErrorKind::NoncharacterCharacterReference)
+      } else if char_code == 0x0d
+        || ((char_code < 0x20 || (0x7f..=0x9f).contains(&char_code))
+          && !matches!(char_code, 0x09 | 0x0a | 0x0c))
+      {
+        Some(DecodeErrorKind::ControlCharacterReference)
+      } else {
+        None
+      };
+      let char_code = match char_code {
+        0x80..=0x9f => match C1_REPLACEMENTS[(char_code - 0x80) as usize] {
+          0 => char_code,
+          replacement => replacement,
+        },
+        _ => char_code,
+      };
+      // the surrogates have been excluded
+      (
+        char::from_u32(char_code).unwrap_or(char::REPLACEMENT_CHARACTER),
+        error,
+      )
+    }
   }
-  Err(HtmlEntityError::Decode(String::from("Html entity number cannot be empty.")).into())
 }
 
 enum Utf8ParsedData {
@@ -107,68 +254,97 @@ fn loop_utf8_bytes(
   let mut ch: u32 = 0;
   let mut start_index: usize = 0;
   for (index, byte) in bytes.iter().enumerate() {
-    match next_count {
-      0 => {
-        start_index = index;
-        if (byte >> 7) == 0 {
-          let _ = handle(Utf8ParsedData::Correct(*byte as char), (start_index, index));
-        } else {
-          let mut head = byte >> 3;
-          if head == 0b11110 {
-            next_count = 3;
-            ch = ((byte & 0b111) as u32) << (next_count * 6);
+    if next_count > 0 {
+      if (byte >> 6) == 0b10 {
+        next_count -= 1;
+        ch += ((byte & 0b111111) as u32) << (next_count * 6);
+        if next_count == 0 {
+          if let Some(ch) = char::from_u32(ch) {
+            let _ = handle(Utf8ParsedData::Correct(ch), (start_index, index));
           } else {
-            head >>= 1;
-            if head == 0b1110 {
-              next_count = 2;
-              ch = ((byte & 0b1111) as u32) << (next_count * 6);
-            } else {
-              head >>= 1;
-              if head == 0b110 {
-                next_count = 1;
-                ch = ((byte & 0b11111) as u32) << (next_count * 6);
-              } else {
-                // wrong utf8 byte
-                next_count = 0;
-                handle(
-                  Utf8ParsedData::Wrong("Illegal utf8 encoded bytes"),
-                  (start_index, index),
-                )?;
-              }
-            }
+            handle(
+              Utf8ParsedData::Wrong("Illegal encoding utf8 character."),
+              (start_index, index),
+            )?;
           }
         }
+        continue;
       }
-      1..=3 => {
-        if (byte >> 6) == 0b10 {
-          next_count -= 1;
-          ch += ((byte & 0b111111) as u32) << (next_count * 6);
-          if next_count == 0 {
-            if let Some(ch) = char::from_u32(ch) {
-              let _ = handle(Utf8ParsedData::Correct(ch), (start_index, index));
-            } else {
-              handle(
-                Utf8ParsedData::Wrong("Illegal encoding utf8 character."),
-                (start_index, index),
-              )?;
-            }
-          }
+      // wrong utf8, the byte is not a continuation byte and will be parsed again as a leading byte
+      next_count = 0;
+      handle(
+        Utf8ParsedData::Wrong("Illegal utf8 encoded bytes."),
+        (start_index, index - 1),
+      )?;
+    }
+    start_index = index;
+    if (byte >> 7) == 0 {
+      let _ = handle(Utf8ParsedData::Correct(*byte as char), (start_index, index));
+    } else {
+      let mut head = byte >> 3;
+      if head == 0b11110 {
+        next_count = 3;
+        ch = ((byte & 0b111) as u32) << (next_count * 6);
+      } else {
+        head >>= 1;
+        if head == 0b1110 {
+          next_count = 2;
+          ch = ((byte & 0b1111) as u32) << (next_count * 6);
         } else {
-          next_count = 0;
-          // wrong utf8
-          handle(
-            Utf8ParsedData::Wrong("Illegal utf8 encoded bytes."),
-            (start_index, index),
-          )?;
+          head >>= 1;
+          if head == 0b110 {
+            next_count = 1;
+            ch = ((byte & 0b11111) as u32) << (next_count * 6);
+          } else {
+            // wrong utf8 byte
+            handle(
+              Utf8ParsedData::Wrong("Illegal utf8 encoded bytes"),
+              (start_index, index),
+            )?;
+          }
         }
       }
-      // unreachable feature
-      _ => unreachable!(),
     }
   }
+  if next_count > 0 {
+    // the bytes end in the middle of a character
+    handle(
+      Utf8ParsedData::Wrong("Incomplete utf8 encoded bytes."),
+      (start_index, bytes.len() - 1),
+    )?;
+  }
   Ok(())
 }
 
+// the length of the utf8 character by the leading byte, the wrong leading byte is counted as one byte
+#[inline]
+pub(crate) fn utf8_char_len(byte: Byte) -> usize {
+  if (byte >> 3) == 0b11110 {
+    4
+  } else if (byte >> 4) == 0b1110 {
+    3
+  } else if (byte >> 5) == 0b110 {
+    2
+  } else {
+    1
+  }
+}
+
+// the length of the incomplete utf8 character at the end of the bytes, which may be completed by the following bytes
+pub(crate) fn incomplete_utf8_len(bytes: &[Byte]) -> usize {
+  for (count, &byte) in bytes.iter().rev().take(3).enumerate() {
+    if (byte >> 6) == 0b10 {
+      continue;
+    }
+    return if utf8_char_len(byte) > count + 1 {
+      count + 1
+    } else {
+      0
+    };
+  }
+  0
+}
+
 #[inline]
 fn bytes_to_chars(bytes: &[Byte], data: &mut Vec<char>) -> AnyhowResult<()> {
   loop_utf8_bytes(bytes, |result, _| match result {
@@ -315,8 +491,8 @@ fn call_trait_method_byte<'a, T: IBytesTrait>(
 #[derive(Debug)]
 pub struct DecodedData<'b> {
   inner_bytes: Cow<'b, [Byte]>,
-  entities: Vec<(CodeRange, (char, ByteList))>,
-  errors: Vec<(CodeRange, anyhow::Error)>,
+  entities: Vec<(CodeRange, (DecodedChars, ByteList))>,
+  errors: Vec<(CodeRange, DecodeErrorKind)>,
 }
 
 impl<'b> ICodedDataTrait for DecodedData<'b> {}
@@ -338,7 +514,7 @@ impl<'b> DecodedData<'b> {
     self.errors.is_empty()
   }
   // get errors
-  pub fn get_errors(&self) -> &[(CodeRange, anyhow::Error)] {
+  pub fn get_errors(&self) -> &[(CodeRange, DecodeErrorKind)] {
     &self.errors
   }
   // entity count
@@ -365,7 +541,7 @@ impl<'b> DecodedData<'b> {
     if self.entities.is_empty() {
       return self.inner_bytes.clone();
     }
-    return Cow::Owned(self.to_bytes());
+    Cow::Owned(self.to_bytes())
   }
 }
 
@@ -374,7 +550,7 @@ pub trait IBytesTrait {
   fn bytes_len(&self) -> usize;
 }
 
-impl IBytesTrait for (char, ByteList) {
+impl IBytesTrait for (DecodedChars, ByteList) {
   fn byte(&self, index: usize) -> Option<&Byte> {
     self.1.get(index)
   }
@@ -385,30 +561,17 @@ impl IBytesTrait for (char, ByteList) {
 
 impl IBytesTrait for CharEntity {
   fn byte(&self, index: usize) -> Option<&Byte> {
-    let prefix_len = self.prefix_len();
-    if index > prefix_len {
-      // from entity data or
-      let cur_index = index - prefix_len - 1;
-      return match cur_index.cmp(&self.entity_data.len()) {
-        Ordering::Less => self.entity_data.get(cur_index),
-        Ordering::Equal => Some(&b';'),
-        Ordering::Greater => None,
-      };
-    } else if index == 0 {
-      // the first byte
-      return Some(&b'&');
-    } else {
-      // the next prefix bytes
-      match prefix_len {
-        1 => Some(&b'#'),
-        2 => {
-          if index == 1 {
-            return Some(&b'#');
-          }
-          Some(&b'x')
-        }
-        _ => unreachable!(),
-      }
+    let prefix = self.entity_type.prefix().as_bytes();
+    if index < prefix.len() {
+      // the '&' and the prefix bytes
+      return prefix.get(index);
+    }
+    // from entity data or
+    let cur_index = index - prefix.len();
+    match cur_index.cmp(&self.entity_data.len()) {
+      Ordering::Less => self.entity_data.get(cur_index),
+      Ordering::Equal => Some(&b';'),
+      Ordering::Greater => None,
     }
   }
   fn bytes_len(&self) -> usize {
@@ -503,7 +666,7 @@ impl<'a, T: IBytesTrait> Iterator for DataIter<'a, T> {
 
 impl<'a> IntoIterator for &'a DecodedData<'a> {
   type Item = IterDataItem<'a>;
-  type IntoIter = DataIter<'a, (char, ByteList)>;
+  type IntoIter = DataIter<'a, (DecodedChars, ByteList)>;
   fn into_iter(self) -> Self::IntoIter {
     gen_into_iter(&self.inner_bytes, &self.entities)
   }
@@ -523,8 +686,8 @@ impl<'a> IntoIterator for &'a EncodedData<'a> {
  */
 impl<'b> From<&DecodedData<'b>> for StringResult {
   fn from(value: &DecodedData<'b>) -> Self {
-    call_into_string_trait(&value.inner_bytes, &value.entities, |&(ch, _), result| {
-      result.push(ch)
+    call_into_string_trait(&value.inner_bytes, &value.entities, |(chars, _), result| {
+      result.extend(chars.iter())
     })
   }
 }
@@ -561,8 +724,8 @@ impl<'b> From<DecodedData<'b>> for ByteList {
  */
 impl<'b> From<&DecodedData<'b>> for CharListResult {
   fn from(value: &DecodedData<'b>) -> Self {
-    call_into_char_list_trait(&value.inner_bytes, &value.entities, |&(ch, _), result| {
-      result.push(ch)
+    call_into_char_list_trait(&value.inner_bytes, &value.entities, |(chars, _), result| {
+      result.extend_from_slice(chars)
     })
   }
 }
@@ -615,7 +778,7 @@ impl<'b> EncodedData<'b> {
     if self.entities.is_empty() {
       return self.inner_bytes.clone();
     }
-    return Cow::Owned(self.to_bytes());
+    Cow::Owned(self.to_bytes())
   }
 }
 
@@ -683,6 +846,8 @@ pub enum EncodeType {
   Decimal = 0b00100,
   NamedOrHex = 0b00011,
   NamedOrDecimal = 0b00101,
+  /// the fewest bytes of the named, hex and decimal entities, the named entity is preferred then the decimal one if they have the same length
+  Shortest = 0b00111,
 }
 
 #[inline]
@@ -690,11 +855,14 @@ fn filter_entity_set(
   charset: &EntityCharBytes,
   encode_type: &EncodeType,
   ch: &char,
-) -> EncodeFilterReturnData {
-  let encode_type = *encode_type as u8;
+) -> (bool, Option<EntityChoice>) {
   if let Some(&v) = charset.get(ch) {
-    if (encode_type & EncodeType::Named as u8) > 0 {
-      return (true, Some((EntityType::Named, Cow::from(v))));
+    // the shortest entity is chosen by the `encode_char` method
+    if matches!(encode_type, EncodeType::Shortest) {
+      return (true, None);
+    }
+    if (*encode_type as u8 & EncodeType::Named as u8) > 0 {
+      return (true, Some(EntityChoice::Named(v)));
     }
     return (true, None);
   }
@@ -702,46 +870,190 @@ fn filter_entity_set(
 }
 
 /// The character set that needs to be encoded to html entity.
-#[derive(Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub enum CharacterSet {
   /// all characters
-  All = 1,
-  /// non ASCII, code point > 0xff                
-  NonASCII = 2,
+  All,
+  /// code point > 0xff, which is not non ascii as the name says
+  #[deprecated(
+    note = "use `NonLatin1` for the same characters, or `NonAscii` to encode the characters between 0x80 and 0xff too"
+  )]
+  NonASCII,
   /// html: '<','>','&'    
   #[default]
-  Html = 3,
+  Html,
   /// special characters: '<','>','&', '\'', '"'                
-  SpecialChars = 4,
+  SpecialChars,
+  /// html and code point > 0xff
+  #[deprecated(
+    note = "use `HtmlAndNonLatin1` for the same characters, or `HtmlAndNonAscii` to encode the characters between 0x80 and 0xff too"
+  )]
+  HtmlAndNonASCII,
+  /// special characters and code point > 0xff
+  #[deprecated(
+    note = "use `SpecialCharsAndNonLatin1` for the same characters, or `SpecialCharsAndNonAscii` to encode the characters between 0x80 and 0xff too"
+  )]
+  SpecialCharsAndNonASCII,
+  /// non ascii, code point > 0x7f
+  NonAscii,
+  /// non latin-1, code point > 0xff
+  NonLatin1,
   /// html and non ascii
-  HtmlAndNonASCII = 5,
+  HtmlAndNonAscii,
   /// special characters and non ascii
-  SpecialCharsAndNonASCII = 6,
+  SpecialCharsAndNonAscii,
+  /// html and non latin-1
+  HtmlAndNonLatin1,
+  /// special characters and non latin-1
+  SpecialCharsAndNonLatin1,
+  /// the user-defined characters, see the `charset::CharSet`
+  Custom(CharSet),
+  /// the characters with the unicode property, such as a general category, a script or `Emoji_Presentation`,
+  /// the property can only be looked up with the `unicode-properties` feature
+  UnicodeProperty(UnicodeProperty),
+  /// the characters can't be represented in the legacy output encoding, see the `legacy::LegacyEncoding`
+  NotRepresentableIn(LegacyEncoding),
+  /// the bidi control characters used by the "Trojan Source" attacks: U+061C, U+200E, U+200F, U+202A-U+202E and U+2066-U+2069,
+  /// they are always encoded into numeric entities
+  BidiControls,
+  /// the zero width characters: U+200B-U+200D, U+2060 and U+FEFF, they are always encoded into numeric entities
+  ZeroWidth,
+  /// the bidi controls and the zero width characters
+  Invisible,
+  /// html and the invisible characters
+  HtmlAndInvisible,
+  /// special characters and the invisible characters
+  SpecialCharsAndInvisible,
+  /// html and the markdown characters which can start the inline formatting: '*', '_', '`', '[', ']', '|', '~' and '\\',
+  /// the markdown characters are always encoded into numeric entities.
+  /// The characters which only start the blocks at the start of a line are encoded by `EscapeContext::Markdown`
+  Markdown,
+}
+
+// the markdown characters which can start the inline formatting anywhere, the backslash escapes and the gfm strikethrough and tables included
+fn is_markdown_char(ch: &char) -> bool {
+  matches!(ch, '*' | '_' | '`' | '[' | ']' | '|' | '~' | '\\')
+}
+
+// the markdown characters which start the blocks at the start of a line, such as the headings, the list items and the indented code blocks
+fn is_markdown_block_marker(content: &[Byte], index: usize) -> bool {
+  let is_line_start = |index: usize| index == 0 || matches!(content[index - 1], b'\n' | b'\r');
+  match content[index] {
+    b' ' | b'\t' | b'#' | b'-' | b'+' | b'=' => is_line_start(index),
+    // the ordered list items, e.g. `1.` and `1)`
+    b'.' | b')' => {
+      let digits = content[..index]
+        .iter()
+        .rev()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+      digits > 0 && is_line_start(index - digits)
+    }
+    _ => false,
+  }
+}
+
+// the bidi control characters, the arabic letter mark, the implicit marks, the embeddings, the overrides and the isolates
+fn is_bidi_control(ch: &char) -> bool {
+  matches!(
+    ch,
+    '\u{61c}' | '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}' | '\u{2066}'..='\u{2069}'
+  )
+}
+
+// the zero width space, non-joiner, joiner, word joiner and no-break space (byte order mark)
+fn is_zero_width(ch: &char) -> bool {
+  matches!(ch, '\u{200b}'..='\u{200d}' | '\u{2060}' | '\u{feff}')
+}
+
+// the invisible characters are encoded into numeric entities even if they have named entities, so they are visible in the source
+fn filter_numeric(
+  need_encode: bool,
+  ch: &char,
+  encode_type: &EncodeType,
+  format: &NumericFormat,
+) -> (bool, Option<EntityChoice>) {
+  if !need_encode {
+    return (false, None);
+  }
+  (true, Some(choose_numeric(*ch as u32, encode_type, format)))
+}
+
+// filter the character by the first character set, then the second one
+fn filter_either(
+  first: &CharacterSet,
+  second: &CharacterSet,
+  ch: &char,
+  encode_type: &EncodeType,
+  format: &NumericFormat,
+) -> (bool, Option<EntityChoice>) {
+  let result = first.choose(ch, encode_type, format);
+  if result.0 {
+    return result;
+  }
+  second.choose(ch, encode_type, format)
 }
 
+#[allow(deprecated)]
 impl CharacterSet {
   /// check if a character need encode by the encode type, and encode it if nessessary.
   pub fn filter(&self, ch: &char, encode_type: &EncodeType) -> EncodeFilterReturnData {
+    let (need_encode, entity) = self.choose(ch, encode_type, &NumericFormat::default());
+    (need_encode, entity.map(EntityChoice::into_entity))
+  }
+  /// Similar to the `filter` method, but the numeric entities are written in the numeric format,
+  /// so the entity is always returned if the character needs to be encoded and the encode type allows.
+  pub fn filter_with_format(
+    &self,
+    ch: &char,
+    encode_type: &EncodeType,
+    format: &NumericFormat,
+  ) -> EncodeFilterReturnData {
+    let (need_encode, entity) = self.choose(ch, encode_type, format);
+    if !need_encode {
+      return (false, None);
+    }
+    let char_code = *ch as u32;
+    let entity = entity
+      .or_else(|| choose_entity(find_named_entity(char_code), char_code, encode_type, format));
+    (true, entity.map(EntityChoice::into_entity))
+  }
+  // check if a character need encode, the entity is formatted only when it's written
+  pub(crate) fn choose(
+    &self,
+    ch: &char,
+    encode_type: &EncodeType,
+    format: &NumericFormat,
+  ) -> (bool, Option<EntityChoice>) {
     use CharacterSet::*;
     match self {
       SpecialChars => filter_entity_set(&SPECIAL_BYTES, encode_type, ch),
       Html => filter_entity_set(&HTML_BYTES, encode_type, ch),
-      NonASCII => (*ch as u32 > 0xff, None),
-      HtmlAndNonASCII => {
-        let result = CharacterSet::NonASCII.filter(ch, encode_type);
-        if result.0 {
-          return result;
-        }
-        CharacterSet::Html.filter(ch, encode_type)
+      NonAscii => (*ch as u32 > 0x7f, None),
+      NonLatin1 | NonASCII => (*ch as u32 > 0xff, None),
+      HtmlAndNonAscii => filter_either(&NonAscii, &Html, ch, encode_type, format),
+      SpecialCharsAndNonAscii => filter_either(&NonAscii, &SpecialChars, ch, encode_type, format),
+      HtmlAndNonLatin1 | HtmlAndNonASCII => {
+        filter_either(&NonLatin1, &Html, ch, encode_type, format)
       }
-      SpecialCharsAndNonASCII => {
-        let result = CharacterSet::NonASCII.filter(ch, encode_type);
-        if result.0 {
-          return result;
-        }
-        CharacterSet::SpecialChars.filter(ch, encode_type)
+      SpecialCharsAndNonLatin1 | SpecialCharsAndNonASCII => {
+        filter_either(&NonLatin1, &SpecialChars, ch, encode_type, format)
       }
       All => (true, None),
+      Custom(set) => (set.contains(ch), None),
+      CharacterSet::UnicodeProperty(property) => (property.contains(ch), None),
+      NotRepresentableIn(encoding) => (!encoding.is_representable(ch), None),
+      BidiControls => filter_numeric(is_bidi_control(ch), ch, encode_type, format),
+      ZeroWidth => filter_numeric(is_zero_width(ch), ch, encode_type, format),
+      Invisible => filter_numeric(Invisible.contains(ch), ch, encode_type, format),
+      HtmlAndInvisible => filter_either(&Invisible, &Html, ch, encode_type, format),
+      SpecialCharsAndInvisible => filter_either(&Invisible, &SpecialChars, ch, encode_type, format),
+      Markdown => {
+        if is_markdown_char(ch) {
+          return filter_numeric(true, ch, encode_type, format);
+        }
+        Html.choose(ch, encode_type, format)
+      }
     }
   }
   /// Check if the character is in the charcter set
@@ -750,21 +1062,565 @@ impl CharacterSet {
     match self {
       SpecialChars => SPECIAL_BYTES.get(ch).is_some(),
       Html => HTML_BYTES.get(ch).is_some(),
-      NonASCII => *ch as u32 > 0xff,
-      HtmlAndNonASCII => CharacterSet::NonASCII.contains(ch) || CharacterSet::Html.contains(ch),
-      SpecialCharsAndNonASCII => {
-        CharacterSet::NonASCII.contains(ch) || CharacterSet::SpecialChars.contains(ch)
+      NonAscii => *ch as u32 > 0x7f,
+      NonLatin1 | NonASCII => *ch as u32 > 0xff,
+      HtmlAndNonAscii => NonAscii.contains(ch) || Html.contains(ch),
+      SpecialCharsAndNonAscii => NonAscii.contains(ch) || SpecialChars.contains(ch),
+      HtmlAndNonLatin1 | HtmlAndNonASCII => NonLatin1.contains(ch) || Html.contains(ch),
+      SpecialCharsAndNonLatin1 | SpecialCharsAndNonASCII => {
+        NonLatin1.contains(ch) || SpecialChars.contains(ch)
       }
       All => true,
+      Custom(set) => set.contains(ch),
+      CharacterSet::UnicodeProperty(property) => property.contains(ch),
+      NotRepresentableIn(encoding) => !encoding.is_representable(ch),
+      BidiControls => is_bidi_control(ch),
+      ZeroWidth => is_zero_width(ch),
+      Invisible => is_bidi_control(ch) || is_zero_width(ch),
+      HtmlAndInvisible => Invisible.contains(ch) || Html.contains(ch),
+      SpecialCharsAndInvisible => Invisible.contains(ch) || SpecialChars.contains(ch),
+      Markdown => is_markdown_char(ch) || Html.contains(ch),
+    }
+  }
+}
+
+/// EncodeOptions: the options used by the `encode_with_options` and `encode_with_options_to` methods.
+#[derive(Default)]
+pub struct EncodeOptions {
+  /// the encode type
+  pub encode_type: EncodeType,
+  /// the character set that needs to be encoded
+  pub charset: CharacterSet,
+  /// encode for the xml document: only the predefined xml entities are used,
+  /// the characters which can't be written literally are always encoded as numeric references,
+  /// and the characters not matching the `Char` production are replaced by U+FFFD.
+  pub xml_version: Option<XmlVersion>,
+  /// the preferred entity name when a character has more than one named entity
+  pub name_preference: EntityNamePreference,
+  /// only the named entities in the entity set are used, the characters whose names are out of the set are encoded into numeric entities,
+  /// which are the hex entities when the encode type is `EncodeType::Named`,
+  /// it's ignored if the `xml_version` is set
+  pub entity_set: EntitySet,
+  /// the letter case and the zero padding of the numeric entities
+  pub numeric_format: NumericFormat,
+  /// keep the character references in the content, the '&' is only encoded if it doesn't start a character reference
+  /// which can be decoded by `Entity::decode_in` with the `entity_set`, or a predefined entity or a numeric reference
+  /// to a legal `Char` if the `xml_version` is set, so encoding the encoded content again doesn't change it
+  pub keep_references: bool,
+  /// how the code points forbidden in html documents are handled, they're kept by default
+  pub forbidden_code_points: ForbiddenCodePointPolicy,
+}
+
+/// NumericFormat: how the digits of the numeric entities are written, the default format is the lowercase hex without padding, e.g. `&#x3c;`.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+///
+/// let format = NumericFormat {
+///   uppercase_digits: true,
+///   uppercase_prefix: true,
+///   min_width: 4,
+/// };
+/// let char_entity = encode_char_with_format(&'<', &EncodeType::Hex, &format);
+/// assert_eq!(char_entity.unwrap().to_string(), "&#X003C;");
+/// let char_entity = encode_char_with_format(&'<', &EncodeType::Decimal, &format);
+/// assert_eq!(char_entity.unwrap().to_string(), "&#0060;");
+/// ```
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub struct NumericFormat {
+  /// write the hex digits in uppercase, e.g. `&#x3C;`
+  pub uppercase_digits: bool,
+  /// write the hex prefix in uppercase, e.g. `&#X3c;`
+  pub uppercase_prefix: bool,
+  /// pad the digits of the hex and decimal entities with leading zeros to the width, e.g. `&#x003c;` with the width 4
+  pub min_width: usize,
+}
+
+impl NumericFormat {
+  // write the hex or decimal digits of the character code
+  fn write_digits(&self, f: &mut impl Write, char_code: u32, is_hex: bool) -> std::fmt::Result {
+    let width = self.min_width;
+    match (is_hex, self.uppercase_digits) {
+      (true, true) => write!(f, "{:0width$X}", char_code, width = width),
+      (true, false) => write!(f, "{:0width$x}", char_code, width = width),
+      (false, _) => write!(f, "{:0width$}", char_code, width = width),
+    }
+  }
+  // the count of the hex or decimal digits of the character code
+  fn digits_len(&self, char_code: u32, is_hex: bool) -> usize {
+    let radix = if is_hex { 16 } else { 10 };
+    let mut code = char_code;
+    let mut len = 1;
+    while code >= radix {
+      code /= radix;
+      len += 1;
+    }
+    len.max(self.min_width)
+  }
+}
+
+// the entity chosen for a character, the digits of the numeric entity are only formatted when it's written
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum EntityChoice {
+  Named(Bytes),
+  Numeric(EntityType, u32, NumericFormat),
+}
+
+impl EntityChoice {
+  // the length of the entity data between the prefix and the ending ';'
+  fn data_len(&self) -> usize {
+    match self {
+      EntityChoice::Named(name) => name.len(),
+      EntityChoice::Numeric(entity_type, char_code, format) => {
+        format.digits_len(*char_code, *entity_type != EntityType::Decimal)
+      }
+    }
+  }
+  // write the entity into the formatter without allocation
+  pub(crate) fn write_to(&self, f: &mut impl Write) -> std::fmt::Result {
+    match self {
+      EntityChoice::Named(name) => write_entity_fmt(f, &EntityType::Named, name),
+      EntityChoice::Numeric(entity_type, char_code, format) => {
+        f.write_str(entity_type.prefix())?;
+        format.write_digits(f, *char_code, *entity_type != EntityType::Decimal)?;
+        f.write_char(';')
+      }
+    }
+  }
+  // the entity type and the entity data
+  fn into_entity(self) -> (EntityType, Cow<'static, [Byte]>) {
+    match self {
+      EntityChoice::Named(name) => (EntityType::Named, Cow::from(name)),
+      EntityChoice::Numeric(entity_type, char_code, format) => {
+        let mut digits = String::with_capacity(self.data_len());
+        // writing into a string never fails
+        let _ = format.write_digits(&mut digits, char_code, entity_type != EntityType::Decimal);
+        (entity_type, Cow::Owned(digits.into_bytes()))
+      }
+    }
+  }
+}
+
+// the numeric entity of the character code, the hex is preferred
+fn choose_numeric(
+  char_code: u32,
+  encode_type: &EncodeType,
+  format: &NumericFormat,
+) -> EntityChoice {
+  let hex_type = if format.uppercase_prefix {
+    EntityType::UpperHex
+  } else {
+    EntityType::Hex
+  };
+  let entity_type = match encode_type {
+    EncodeType::Decimal | EncodeType::NamedOrDecimal => EntityType::Decimal,
+    // the hex prefix has one more byte
+    EncodeType::Shortest
+      if format.digits_len(char_code, true) + 1 >= format.digits_len(char_code, false) =>
+    {
+      EntityType::Decimal
+    }
+    _ => hex_type,
+  };
+  EntityChoice::Numeric(entity_type, char_code, *format)
+}
+
+// the entity of the character code allowed by the encode type, the name is used for the named entity
+fn choose_entity(
+  name: Option<Bytes>,
+  char_code: u32,
+  encode_type: &EncodeType,
+  format: &NumericFormat,
+) -> Option<EntityChoice> {
+  let encode_type_bits = *encode_type as u8;
+  if (encode_type_bits & (EncodeType::Named as u8)) > 0 {
+    if let Some(name) = name {
+      let is_shorter = || {
+        let numeric = choose_numeric(char_code, encode_type, format);
+        // the numeric entity has the '#' or '#x' prefix
+        let prefix_len = if let EntityChoice::Numeric(EntityType::Decimal, ..) = numeric {
+          1
+        } else {
+          2
+        };
+        name.len() <= numeric.data_len() + prefix_len
+      };
+      if !matches!(encode_type, EncodeType::Shortest) || is_shorter() {
+        return Some(EntityChoice::Named(name));
+      }
+    }
+  }
+  if (encode_type_bits & (EncodeType::Hex as u8 | EncodeType::Decimal as u8)) > 0 {
+    return Some(choose_numeric(char_code, encode_type, format));
+  }
+  None
+}
+
+// the numeric entity of the character code, the hex is preferred
+fn numeric_entity(
+  char_code: u32,
+  encode_type: &EncodeType,
+  format: &NumericFormat,
+) -> (EntityType, Cow<'static, [Byte]>) {
+  choose_numeric(char_code, encode_type, format).into_entity()
+}
+
+// the entity of the character code allowed by the encode type, the name is used for the named entity
+fn format_entity(
+  name: Option<Bytes>,
+  char_code: u32,
+  encode_type: &EncodeType,
+  format: &NumericFormat,
+) -> Option<(EntityType, Cow<'static, [Byte]>)> {
+  choose_entity(name, char_code, encode_type, format).map(EntityChoice::into_entity)
+}
+
+impl EncodeOptions {
+  // the byte ranges of the character references kept by the `keep_references` option
+  fn kept_references(&self, content: &[Byte]) -> Vec<(usize, usize)> {
+    let mut references = vec![];
+    if !self.keep_references {
+      return references;
+    }
+    let mut index = 0;
+    while let Some(offset) = content[index..].iter().position(|&byte| byte == b'&') {
+      let start_index = index + offset;
+      index = start_index + 1;
+      if let Some(len) = self.reference_len(&content[start_index..]) {
+        references.push((start_index, start_index + len));
+        index = start_index + len;
+      }
+    }
+    references
+  }
+  // the length of the character reference at the start of the content, which must end with ';',
+  // only the predefined entities and the references to the legal `Char` are kept in xml,
+  // otherwise the reference must be decoded by `Entity::decode_in` with the entity set
+  fn reference_len(&self, content: &[Byte]) -> Option<usize> {
+    let name_len = content[1..]
+      .iter()
+      .enumerate()
+      .take_while(|&(index, byte)| byte.is_ascii_alphanumeric() || (index == 0 && *byte == b'#'))
+      .count();
+    if name_len == 0 || content.get(name_len + 1) != Some(&b';') {
+      return None;
+    }
+    let name = &content[1..=name_len];
+    match (self.xml_version, name.split_first()) {
+      (Some(version), Some((b'#', rest))) => {
+        // the xml only allows the lowercase 'x'
+        let (digits, radix) = match rest.split_first() {
+          Some((b'x', digits)) => (digits, 16),
+          _ => (rest, 10),
+        };
+        if digits.is_empty() || !digits.iter().all(|byte| (*byte as char).is_digit(radix)) {
+          return None;
+        }
+        char::from_u32(parse_char_code(digits, radix)).filter(|&ch| version.is_char(ch))?;
+      }
+      (Some(_), _) => {
+        XML_ENTITIES.iter().find(|(entity, _)| *entity == name)?;
+      }
+      (None, _) => {
+        Entity::decode_in(name, &self.entity_set).ok()?;
+      }
+    }
+    Some(name_len + 2)
+  }
+  // filter the character by the options, the characters in the kept references are not encoded
+  fn filter_at(
+    &self,
+    content: &[Byte],
+    references: &[(usize, usize)],
+    ch: &char,
+    next_index: usize,
+  ) -> EncodeFilterReturnData {
+    let index = next_index - ch.len_utf8();
+    let position = references.partition_point(|&(_, end)| end <= index);
+    if references
+      .get(position)
+      .is_some_and(|&(start, _)| start <= index)
+    {
+      return (false, None);
+    }
+    self.filter_char(ch, content.get(next_index) == Some(&b'\n'))
+  }
+  /// check if a character need encode by the options, and encode it if nessessary.
+  ///
+  /// The next character is unknown, so a CR is handled as a lone CR by the `forbidden_code_points` policy.
+  pub fn filter(&self, ch: &char) -> EncodeFilterReturnData {
+    self.filter_char(ch, false)
+  }
+  // filter the character, the CR followed by LF is not a forbidden code point
+  fn filter_char(&self, ch: &char, followed_by_lf: bool) -> EncodeFilterReturnData {
+    if let Some(entity) =
+      self
+        .forbidden_code_points
+        .entity(ch, followed_by_lf, &self.encode_type, &self.numeric_format)
+    {
+      return (true, Some(entity));
+    }
+    let version = match self.xml_version {
+      Some(version) => version,
+      None => {
+        let (need_encode, entity) =
+          self
+            .charset
+            .choose(ch, &self.encode_type, &self.numeric_format);
+        // the numeric entities required by the character set, e.g. `CharacterSet::Invisible`
+        if let Some(entity @ EntityChoice::Numeric(..)) = entity {
+          return (true, Some(entity.into_entity()));
+        }
+        if !need_encode
+          || (matches!(self.name_preference, EntityNamePreference::First)
+            && self.entity_set == EntitySet::Html5
+            && self.numeric_format == NumericFormat::default())
+        {
+          return (need_encode, entity.map(EntityChoice::into_entity));
+        }
+        let char_code = *ch as u32;
+        let name = self.name_preference.find_name_in(*ch, &self.entity_set);
+        let entity = choose_entity(name, char_code, &self.encode_type, &self.numeric_format)
+          .or_else(|| {
+            // the names out of the entity set fall back to the hex entities
+            find_named_entity(char_code)
+              .map(|_| choose_numeric(char_code, &EncodeType::Hex, &self.numeric_format))
+          });
+        return match entity {
+          Some(entity) => (true, Some(entity.into_entity())),
+          None => (false, None),
+        };
+      }
+    };
+    if !version.is_literal_char(*ch) {
+      let char_code = if version.is_char(*ch) {
+        *ch as u32
+      } else {
+        char::REPLACEMENT_CHARACTER as u32
+      };
+      return (
+        true,
+        Some(numeric_entity(
+          char_code,
+          &self.encode_type,
+          &self.numeric_format,
+        )),
+      );
+    }
+    if !self.charset.filter(ch, &self.encode_type).0 {
+      return (false, None);
+    }
+    // only the predefined entities can be used
+    let name = XML_ENTITIES
+      .iter()
+      .find(|(_, chars)| chars[0] == *ch)
+      .map(|&(name, _)| name);
+    match format_entity(name, *ch as u32, &self.encode_type, &self.numeric_format) {
+      Some(entity) => (true, Some(entity)),
+      None => (false, None),
+    }
+  }
+}
+
+/// ForbiddenCodePointPolicy: how the `forbidden_code_points` option of the `EncodeOptions` handles the code points forbidden in html documents.
+///
+/// The policy is applied by the `encode_with_options`, `encode_with_options_to` and `encode_chars_with_options` methods,
+/// which are the variants of the `encode`, `encode_to` and `encode_chars_with` methods taking the options.
+///
+/// The forbidden code points are the C0 controls except the tab, LF and FF, the lone CR which isn't followed by LF and is normalized into LF by the html parsers,
+/// the delete and C1 controls U+007F-U+009F, and the noncharacters U+FDD0-U+FDEF, U+xFFFE and U+xFFFF.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ForbiddenCodePointPolicy {
+  /// keep the code points, which is the behaviour of the `encode` method
+  #[default]
+  Keep,
+  /// encode the code points into numeric entities by the encode type, `EncodeType::Named` uses the hex entities.
+  /// The references are reported as parse errors by the html parsers, which also decode `&#x0;` into U+FFFD
+  /// and remap the C1 controls having a windows-1252 mapping, use `Replace` to write U+FFFD instead.
+  Encode,
+  /// replace the code points with U+FFFD, which is encoded by the character set like other characters
+  Replace,
+  /// remove the code points
+  Strip,
+  /// return the `EncodeError::ForbiddenCodePoint` error of the first forbidden code point
+  Error,
+}
+
+impl ForbiddenCodePointPolicy {
+  // replace, strip or check the forbidden code points in the utf-8 bytes, the wrong utf-8 bytes are kept
+  fn apply<'a>(&self, content: &'a [Byte]) -> Result<Cow<'a, [Byte]>, EncodeError> {
+    if matches!(self, Self::Keep | Self::Encode) {
+      return Ok(Cow::from(content));
+    }
+    let mut data: Option<ByteList> = None;
+    let mut error = None;
+    // the bytes before the index are written or removed
+    let mut next_index = 0;
+    let _ = loop_utf8_bytes(content, |result, (start_index, end_index)| {
+      if let Utf8ParsedData::Correct(ch) = result {
+        let followed_by_lf = content.get(end_index + 1) == Some(&b'\n');
+        if error.is_none() && is_forbidden_code_point(ch, followed_by_lf) {
+          if *self == Self::Error {
+            error = Some(EncodeError::ForbiddenCodePoint(start_index, ch));
+            return Ok(());
+          }
+          let data = data.get_or_insert_with(|| Vec::with_capacity(content.len()));
+          data.extend_from_slice(&content[next_index..start_index]);
+          if *self == Self::Replace {
+            data.extend_from_slice("\u{fffd}".as_bytes());
+          }
+          next_index = end_index + 1;
+        }
+      }
+      Ok(())
+    });
+    if let Some(error) = error {
+      return Err(error);
+    }
+    match data {
+      Some(mut data) => {
+        data.extend_from_slice(&content[next_index..]);
+        Ok(Cow::Owned(data))
+      }
+      None => Ok(Cow::from(content)),
+    }
+  }
+  // the numeric entity of the forbidden code point by the encode policy, `None` if the character set decides
+  fn entity(
+    &self,
+    ch: &char,
+    followed_by_lf: bool,
+    encode_type: &EncodeType,
+    format: &NumericFormat,
+  ) -> Option<(EntityType, Cow<'static, [Byte]>)> {
+    if *self != Self::Encode || !is_forbidden_code_point(*ch, followed_by_lf) {
+      return None;
     }
+    Some(numeric_entity(*ch as u32, encode_type, format))
   }
 }
 
-#[derive(PartialEq, Eq, Debug)]
+/// EscapeContext: where the encoded content will be placed in the html document, each context encodes the minimal set of characters which can break out of it.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub enum EscapeContext {
+  /// the text content of the elements: '<', '>', '&'
+  #[default]
+  Text,
+  /// the double-quoted attribute values: '"', '&'
+  DoubleQuotedAttribute,
+  /// the single-quoted attribute values: '\'', '&'
+  SingleQuotedAttribute,
+  /// the unquoted attribute values: the ascii whitespaces, '"', '\'', '=', '<', '>', '`', '&'.
+  /// An empty unquoted value can't be made safe by encoding, so it should be quoted.
+  UnquotedAttribute,
+  /// the raw text elements which decode the character references, such as `<title>` and `<textarea>`: '<', '&'
+  RcData,
+  /// the html comments: '<', '>', which is enough to break the `<!--`, `-->` and `--!>` sequences.
+  /// The character references are not decoded in comments, so the encoded content can't be decoded back.
+  Comment,
+  /// the `srcdoc` attribute value of the `<iframe>`, the content is encoded as the text of the inner document first,
+  /// then encoded again as a double-quoted attribute value.
+  Srcdoc,
+  /// the markdown text rendered into html, the characters of `CharacterSet::Markdown`, and the characters which start the blocks
+  /// at the start of a line: the indentations, '#', '-', '+', '=', and the '.' or ')' after the digits of the ordered list items.
+  /// The markdown characters are encoded into numeric entities, so the text is rendered literally by CommonMark and GFM.
+  Markdown,
+}
+
+impl EscapeContext {
+  /// Check if the character needs to be encoded in the context.
+  pub fn contains(&self, ch: &char) -> bool {
+    use EscapeContext::*;
+    match self {
+      Text => matches!(ch, '<' | '>' | '&'),
+      DoubleQuotedAttribute => matches!(ch, '"' | '&'),
+      SingleQuotedAttribute => matches!(ch, '\'' | '&'),
+      UnquotedAttribute => matches!(
+        ch,
+        '\t' | '\n' | '\x0c' | '\r' | ' ' | '"' | '\'' | '=' | '<' | '>' | '`' | '&'
+      ),
+      RcData => matches!(ch, '<' | '&'),
+      Comment => matches!(ch, '<' | '>'),
+      Srcdoc => Text.contains(ch) || DoubleQuotedAttribute.contains(ch),
+      Markdown => CharacterSet::Markdown.contains(ch),
+    }
+  }
+  /// check if a character need encode in the context, the characters without a named entity are encoded into hex entities when the encode type is `EncodeType::Named`.
+  ///
+  /// The characters of the text in `EscapeContext::Srcdoc` are escaped twice, e.g. `<` is encoded into `&amp;lt;`.
+  pub fn filter(&self, ch: &char, encode_type: &EncodeType) -> EncodeFilterReturnData {
+    self.filter_with_format(ch, encode_type, &NumericFormat::default())
+  }
+  /// Similar to the `filter` method, but the numeric entities are written in the numeric format.
+  pub fn filter_with_format(
+    &self,
+    ch: &char,
+    encode_type: &EncodeType,
+    format: &NumericFormat,
+  ) -> EncodeFilterReturnData {
+    if !self.contains(ch) {
+      return (false, None);
+    }
+    if *self == EscapeContext::Markdown {
+      return CharacterSet::Markdown.filter_with_format(ch, encode_type, format);
+    }
+    let char_code = *ch as u32;
+    // the character must be encoded to avoid breaking out the context
+    let entity = choose_entity(find_named_entity(char_code), char_code, encode_type, format)
+      .unwrap_or_else(|| choose_numeric(char_code, &EncodeType::Hex, format));
+    let (entity_type, entity_data) = entity.into_entity();
+    if *self == EscapeContext::Srcdoc && EscapeContext::Text.contains(ch) {
+      // the entity in the text is escaped again by the attribute value, so its '&' becomes `&amp;`
+      let mut data = entity_type.prefix().as_bytes()[1..].to_vec();
+      data.extend_from_slice(&entity_data);
+      return (true, Some((EntityType::AmpEscaped, Cow::Owned(data))));
+    }
+    (true, Some((entity_type, entity_data)))
+  }
+  // filter the character by the position in the content, the markdown block markers are checked at the start of a line
+  fn filter_at(
+    &self,
+    content: &[Byte],
+    ch: &char,
+    encode_type: &EncodeType,
+    format: &NumericFormat,
+    next_index: usize,
+  ) -> EncodeFilterReturnData {
+    if *self == EscapeContext::Markdown
+      && ch.is_ascii()
+      && is_markdown_block_marker(content, next_index - 1)
+    {
+      return (true, Some(numeric_entity(*ch as u32, encode_type, format)));
+    }
+    self.filter_with_format(ch, encode_type, format)
+  }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum EntityType {
   Named,
   Hex,
   Decimal,
+  /// the hex entity with the uppercase prefix, e.g. `&#X3C;`
+  UpperHex,
+  /// the entity whose '&' is escaped again, e.g. `&amp;lt;` of `EscapeContext::Srcdoc`,
+  /// the entity data is the inner entity between its '&' and ';', such as `lt` or `#x3c`
+  AmpEscaped,
+}
+
+impl EntityType {
+  // the bytes before the entity data
+  fn prefix(&self) -> &'static str {
+    match self {
+      EntityType::Named => "&",
+      EntityType::Hex => "&#x",
+      EntityType::UpperHex => "&#X",
+      EntityType::Decimal => "&#",
+      EntityType::AmpEscaped => "&amp;",
+    }
+  }
 }
 
 /// CharEntity struct
@@ -777,45 +1633,22 @@ pub struct CharEntity {
 impl CharEntity {
   // prefix len
   pub fn prefix_len(&self) -> usize {
-    match &self.entity_type {
-      EntityType::Named => 0,
-      EntityType::Hex => 2,
-      EntityType::Decimal => 1,
-    }
+    // the prefix bytes after the '&'
+    self.entity_type.prefix().len() - 1
+  }
+  /// Get the entity type, which tells the named, hex or decimal entity is chosen.
+  pub fn entity_type(&self) -> &EntityType {
+    &self.entity_type
   }
   // write bytes
   pub fn write_bytes(&self, bytes: &mut ByteList) {
-    bytes.push(b'&');
-    match &self.entity_type {
-      EntityType::Named => {
-        // nothing to do
-      }
-      EntityType::Hex => {
-        bytes.push(b'#');
-        bytes.push(b'x');
-      }
-      EntityType::Decimal => {
-        bytes.push(b'#');
-      }
-    }
+    bytes.extend_from_slice(self.entity_type.prefix().as_bytes());
     bytes.extend_from_slice(&self.entity_data);
     bytes.push(b';');
   }
   // write chars
   pub fn write_chars(&self, chars: &mut Vec<char>) {
-    chars.push('&');
-    match &self.entity_type {
-      EntityType::Named => {
-        // nothing to do
-      }
-      EntityType::Hex => {
-        chars.push('#');
-        chars.push('x');
-      }
-      EntityType::Decimal => {
-        chars.push('#');
-      }
-    }
+    chars.extend(self.entity_type.prefix().chars());
     for byte in self.entity_data.iter() {
       chars.push(*byte as char);
     }
@@ -823,23 +1656,8 @@ impl CharEntity {
   }
   // write string
   pub fn write_string(&self, code: &mut String) {
-    code.push('&');
-    match &self.entity_type {
-      EntityType::Named => {
-        // nothing to do
-      }
-      EntityType::Hex => {
-        code.push('#');
-        code.push('x');
-      }
-      EntityType::Decimal => {
-        code.push('#');
-      }
-    }
-    for byte in self.entity_data.iter() {
-      code.push(*byte as char);
-    }
-    code.push(';');
+    // writing into a string never fails
+    let _ = write_entity_fmt(code, &self.entity_type, &self.entity_data);
   }
   // to bytes
   pub fn to_bytes(&self) -> ByteList {
@@ -853,11 +1671,23 @@ impl CharEntity {
   }
 }
 
-impl ToString for CharEntity {
-  fn to_string(&self) -> String {
-    let mut code = String::with_capacity(self.entity_data.len() + 2);
-    self.write_string(&mut code);
-    code
+// write the entity into the formatter without allocation
+pub(crate) fn write_entity_fmt(
+  f: &mut impl Write,
+  entity_type: &EntityType,
+  entity_data: &[Byte],
+) -> std::fmt::Result {
+  f.write_str(entity_type.prefix())?;
+  // the entity data is always ascii
+  for &byte in entity_data {
+    f.write_char(byte as char)?;
+  }
+  f.write_char(';')
+}
+
+impl Display for CharEntity {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write_entity_fmt(f, &self.entity_type, &self.entity_data)
   }
 }
 /// Entity struct
@@ -865,164 +1695,282 @@ impl ToString for CharEntity {
 pub struct Entity;
 
 impl Entity {
-  /// Decode html entity utf-8 bytes(does't contain the beginning '&' and the end ';') into the character.
-  pub fn decode(bytes: &[Byte]) -> AnyhowResult<char> {
-    let total = bytes.len();
-    if total == 0 {
-      return Err(
-        HtmlEntityError::Decode(String::from(
-          "Can't decode with an empty bytelist argument.",
-        ))
-        .into(),
-      );
-    }
-    // check type
-    let first = bytes[0];
-    let mut entity_type: EntityType = EntityType::Named;
-    if first.is_ascii_alphabetic() {
-      for ch in &bytes[1..] {
-        if !ch.is_ascii_alphanumeric() {
-          let code = std::str::from_utf8(bytes)?;
-          return Err(
-            HtmlEntityError::Decode(format!(
-							"Html entity name can't contain characters other than English letters or numbers, here is '{}'",
-							code
-						))
-            .into(),
-          );
-        }
+  /// Decode html entity utf-8 bytes(does't contain the beginning '&' and the end ';') into the characters.
+  ///
+  /// Most entities decode into a single character, but some named entities such as `&NotEqualTilde;` or `&fjlig;` decode into two characters.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::entity::Entity;
+  /// use htmlentity::types::AnyhowResult;
+  /// # fn main() -> AnyhowResult<()> {
+  /// assert_eq!(*Entity::decode(b"lt")?, ['<']);
+  /// assert_eq!(*Entity::decode(b"#x3c")?, ['<']);
+  /// assert_eq!(*Entity::decode(b"fjlig")?, ['f', 'j']);
+  /// # Ok(())
+  /// # }
+  /// ```
+  ///
+  /// The typed error `DecodeErrorKind` is returned when the bytes are not a valid html entity.
+  ///
+  /// ```
+  /// use htmlentity::entity::{DecodeErrorKind, Entity};
+  ///
+  /// assert_eq!(Entity::decode(b"foo"), Err(DecodeErrorKind::UnknownNamedCharacterReference));
+  /// assert_eq!(Entity::decode(b"#x"), Err(DecodeErrorKind::AbsenceOfDigitsInNumericCharacterReference));
+  /// ```
+  ///
+  /// The numeric references with the digits are always decoded like the html parsers do, so the parse errors of the code points,
+  /// such as `DecodeErrorKind::NullCharacterReference`, are not returned, they're only reported by the `DecodedData::get_errors` method of `decode_with`.
+  ///
+  /// ```
+  /// use htmlentity::entity::{decode_with, DecodeErrorKind, DecodeOptions, Entity};
+  /// use htmlentity::types::AnyhowResult;
+  /// # fn main() -> AnyhowResult<()> {
+  /// assert_eq!(*Entity::decode(b"#0")?, ['\u{fffd}']);
+  /// assert_eq!(
+  ///   decode_with(b"&#0;", &DecodeOptions::default()).get_errors(),
+  ///   [(0..=3, DecodeErrorKind::NullCharacterReference)]
+  /// );
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn decode(bytes: &[Byte]) -> Result<DecodedChars, DecodeErrorKind> {
+    Entity::decode_in(bytes, &EntitySet::Html5)
+  }
+  /// Similar to the `decode` method, but only the named entities in the entity set can be decoded.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::entity::{DecodeErrorKind, Entity, EntitySet};
+  /// use htmlentity::types::AnyhowResult;
+  /// # fn main() -> AnyhowResult<()> {
+  /// assert_eq!(*Entity::decode_in(b"hellip", &EntitySet::Html4)?, ['\u{2026}']);
+  /// assert_eq!(Entity::decode_in(b"hellip", &EntitySet::Html32), Err(DecodeErrorKind::UnknownNamedCharacterReference));
+  /// assert_eq!(Entity::decode_in(b"apos", &EntitySet::Html4), Err(DecodeErrorKind::UnknownNamedCharacterReference));
+  /// assert_eq!(*Entity::decode_in(b"apos", &EntitySet::Xhtml1)?, ['\'']);
+  /// # Ok(())
+  /// # }
+  /// ```
+  pub fn decode_in(
+    bytes: &[Byte],
+    entity_set: &EntitySet,
+  ) -> Result<DecodedChars, DecodeErrorKind> {
+    let (&first, rest) = bytes
+      .split_first()
+      .ok_or(DecodeErrorKind::UnknownNamedCharacterReference)?;
+    if first == b'#' {
+      // numeric entity, remove the prefix '#' or '#x'
+      let (digits, radix) = match rest.split_first() {
+        Some((b'x' | b'X', digits)) => (digits, 16),
+        _ => (rest, 10),
+      };
+      if digits.is_empty() || !(digits[0] as char).is_digit(radix) {
+        return Err(DecodeErrorKind::AbsenceOfDigitsInNumericCharacterReference);
       }
-    } else if first == b'#' && total > 1 {
-      let second = bytes[1];
-      match second {
-        b'0'..=b'9' => {
-          // decimal
-          for byte in &bytes[2..] {
-            if !byte.is_ascii_digit() {
-              let code = std::str::from_utf8(bytes)?;
-              return Err(
-                HtmlEntityError::Decode(format!(
-                  "Html entity number can't contain characters other than numbers, here is '{}'.",
-                  code
-                ))
-                .into(),
-              );
-            }
-          }
-          entity_type = EntityType::Decimal;
-        }
-        b'x' | b'X' => {
-          // hex
-          if total > 2 {
-            for byte in &bytes[2..] {
-              if !byte.is_ascii_hexdigit() {
-                let code = std::str::from_utf8(bytes)?;
-                return Err(
-                  HtmlEntityError::Decode(format!(
-										"Hexadecimal html entity can't contain characters other than hexadecimal, here is '&{};'.",
-										code
-									))
-                  .into(),
-                );
-              }
-            }
-            entity_type = EntityType::Hex;
-          } else {
-            return Err(
-              HtmlEntityError::Decode(String::from(
-                "Hexadecimal html entity must contain one or more hexadecimal characters.",
-              ))
-              .into(),
-            );
-          }
-        }
-        _ => {
-          return Err(
-            HtmlEntityError::Decode(String::from("Illegal html entity number character format"))
-              .into(),
-          );
-        }
+      if !digits.iter().all(|byte| (*byte as char).is_digit(radix)) {
+        // the reference ends at the first non digit byte, so the ';' is missing
+        return Err(DecodeErrorKind::MissingSemicolonAfterCharacterReference);
       }
-    } else {
-      return Err(
-        HtmlEntityError::Decode(String::from("Illegal html entity character format.")).into(),
-      );
+      return Ok(Cow::Owned(vec![numbers_to_char(digits, radix).0]));
     }
-    // go on the steps
-    match entity_type {
-      // named entity
-      EntityType::Named => {
-        // normal entity characters
-        if let Some(&ch) = NORMAL_NAME_ENTITY_BYTE.get(bytes) {
-          return Ok(ch);
-        }
-        // try to find the entity
-        if let Some(&(start_index, end_index)) = FIRST_LETTER_POSITION.get(&bytes[0]) {
-          if let Some(find_index) = LETTER_ORDERED_ENTITIES[start_index..end_index]
-            .iter()
-            .position(|&(name, _)| name == bytes)
-          {
-            let last_index = start_index + find_index;
-            let (_, code) = LETTER_ORDERED_ENTITIES[last_index];
-            return Ok(code);
-          }
-        }
-        let code = std::str::from_utf8(bytes)?;
-        Err(
-          HtmlEntityError::Decode(format!(
-            "Unable to find corresponding the html entity name '&{};'",
-            code
-          ))
-          .into(),
-        )
-      }
-      // hex entity
-      EntityType::Hex => {
-        // remove the prefix '#x'
-        numbers_to_char(&bytes[2..], 16)
-      }
-      // decimal entity
-      EntityType::Decimal => {
-        // remove the prefix '#'
-        numbers_to_char(&bytes[1..], 10)
-      }
+    // named entity
+    if !bytes.iter().all(Byte::is_ascii_alphanumeric) {
+      return Err(DecodeErrorKind::UnknownNamedCharacterReference);
     }
+    ENTITY_TRIE
+      .get(bytes)
+      .filter(|_| entity_set.contains(bytes))
+      .map(Cow::Borrowed)
+      .ok_or(DecodeErrorKind::UnknownNamedCharacterReference)
   }
   /// Similar to the `decode` method, but takes a character type as an argument.
-  pub fn decode_chars(chars: &[char]) -> AnyhowResult<char> {
-    let total = chars.len();
-    if total == 0 {
-      return Err(
-        HtmlEntityError::Decode(String::from(
-          "Can't decode with an empty character list argument.",
-        ))
-        .into(),
-      );
+  pub fn decode_chars(chars: &[char]) -> Result<DecodedChars, DecodeErrorKind> {
+    match tr_chars_to_utf8_bytes(chars) {
+      Some(bytes) => Entity::decode(&bytes),
+      None => Err(DecodeErrorKind::UnknownNamedCharacterReference),
     }
-    let mut bytes: ByteList = Vec::with_capacity(total);
-    let max_u8 = u8::MAX as u32;
-    let is_non_bytes = chars.iter().any(|ch| {
-      let char_code = *ch as u32;
-      if char_code > max_u8 {
-        true
-      } else {
-        bytes.push(char_code as Byte);
-        false
-      }
-    });
-    if !is_non_bytes {
-      return Entity::decode(&bytes);
-    }
-    Err(
-      HtmlEntityError::Decode(format!(
-        "Unable to find corresponding the html entity name '&{};'",
-        chars.iter().collect::<String>()
-      ))
-      .into(),
-    )
   }
 }
 
+// the named entities of the character code in the ENTITIES
+fn named_entities_of(char_code: u32) -> &'static [EntityPointPair] {
+  let start_index = ENTITIES.partition_point(|&(_, code)| code < char_code);
+  let end_index = ENTITIES.partition_point(|&(_, code)| code <= char_code);
+  &ENTITIES[start_index..end_index]
+}
+
+// find the named entity from the ENTITIES, the first one is short and lowercase
+pub(crate) fn find_named_entity(char_code: u32) -> Option<Bytes> {
+  named_entities_of(char_code).first().map(|&(name, _)| name)
+}
+
+/// EntitySet: the vocabulary of the named entities.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub enum EntitySet {
+  /// the html 3.2 entities: the latin-1 characters and `&amp;`, `&lt;`, `&gt;`, `&quot;`
+  Html32,
+  /// the 252 html4 entities
+  Html4,
+  /// all the WHATWG named character references
+  #[default]
+  Html5,
+  /// the MathML entities, same names as the WHATWG named character references, but the ending ';' can't be omitted
+  MathMl,
+  /// the xhtml 1.0 entities: the html4 entities and `&apos;`, the ending ';' can't be omitted
+  Xhtml1,
+}
+
+impl EntitySet {
+  /// Check if the entity name is in the entity set, the name doesn't contain the beginning '&' and the ending ';'.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::entity::EntitySet;
+  ///
+  /// assert!(EntitySet::Html32.contains(b"copy"));
+  /// assert!(!EntitySet::Html32.contains(b"hellip"));
+  /// assert!(EntitySet::Html4.contains(b"hellip"));
+  /// assert!(!EntitySet::Html4.contains(b"apos"));
+  /// assert!(EntitySet::Xhtml1.contains(b"apos"));
+  /// assert!(EntitySet::Html5.contains(b"NotEqualTilde"));
+  /// ```
+  pub fn contains(&self, name: &[Byte]) -> bool {
+    let is_html4 = || HTML4_ENTITIES.binary_search(&name).is_ok();
+    match self {
+      EntitySet::Html5 | EntitySet::MathMl => ENTITY_TRIE.get(name).is_some(),
+      EntitySet::Html4 => is_html4(),
+      EntitySet::Xhtml1 => name == b"apos" || is_html4(),
+      EntitySet::Html32 => {
+        is_html4()
+          && ENTITY_TRIE
+            .get(name)
+            .is_some_and(|chars| matches!(chars[0], '&' | '<' | '>' | '"' | '\u{a0}'..='\u{ff}'))
+      }
+    }
+  }
+  /// Check if the legacy entities can omit the ending ';' in the `DecodeMode::Html5` mode.
+  pub fn allow_legacy(&self) -> bool {
+    !matches!(self, EntitySet::MathMl | EntitySet::Xhtml1)
+  }
+}
+
+/// EntityNamePreference: which name is used when a character has more than one named entity, e.g. `&nbsp;` and `&NonBreakingSpace;`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum EntityNamePreference {
+  /// the first name in the `ENTITIES` data, which is used by the `encode_char` method
+  #[default]
+  First,
+  /// the shortest name, the lowercase name is preferred if they have the same length
+  Shortest,
+  /// the name defined in html4 if the character has one, otherwise the first name
+  Html4,
+  /// the name without uppercase letters if the character has one, otherwise the first name
+  Lowercase,
+  /// the names of the characters in the map, which must be the names of the characters, otherwise the names are chosen by the fallback preference
+  Custom {
+    /// the preferred names of the characters
+    names: HashMap<char, Bytes>,
+    /// the preference of the characters not in the map
+    fallback: Box<EntityNamePreference>,
+  },
+}
+
+impl EntityNamePreference {
+  /// Find the entity name of the character by the preference.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::entity::EntityNamePreference;
+  /// use std::collections::HashMap;
+  ///
+  /// // the character '\u{a8}' has the names `Dot`, `die`, `DoubleDot` and `uml`
+  /// assert_eq!(EntityNamePreference::First.find_name('\u{a8}'), Some(&b"Dot"[..]));
+  /// assert_eq!(EntityNamePreference::Shortest.find_name('\u{a8}'), Some(&b"die"[..]));
+  /// assert_eq!(EntityNamePreference::Html4.find_name('\u{a8}'), Some(&b"uml"[..]));
+  /// assert_eq!(EntityNamePreference::Lowercase.find_name('\u{a8}'), Some(&b"die"[..]));
+  /// let preference = EntityNamePreference::Custom {
+  ///   names: HashMap::from([('\u{a0}', &b"NonBreakingSpace"[..])]),
+  ///   fallback: Box::new(EntityNamePreference::Shortest),
+  /// };
+  /// assert_eq!(preference.find_name('\u{a0}'), Some(&b"NonBreakingSpace"[..]));
+  /// assert_eq!(preference.find_name('a'), None);
+  /// ```
+  pub fn find_name(&self, ch: char) -> Option<Bytes> {
+    self.find_name_in(ch, &EntitySet::Html5)
+  }
+  /// Similar to the `find_name` method, but only the names in the entity set can be found.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::entity::{EntityNamePreference, EntitySet};
+  ///
+  /// assert_eq!(EntityNamePreference::First.find_name_in('\u{a8}', &EntitySet::Html4), Some(&b"uml"[..]));
+  /// assert_eq!(EntityNamePreference::First.find_name_in('\u{2026}', &EntitySet::Html32), None);
+  /// ```
+  pub fn find_name_in(&self, ch: char, entity_set: &EntitySet) -> Option<Bytes> {
+    let is_all = matches!(entity_set, EntitySet::Html5 | EntitySet::MathMl);
+    let names = || {
+      named_entities_of(ch as u32)
+        .iter()
+        .map(|&(name, _)| name)
+        .filter(move |name| is_all || entity_set.contains(name))
+    };
+    let first = names().next();
+    let is_lowercase = |name: &[Byte]| !name.iter().any(|byte| byte.is_ascii_uppercase());
+    match self {
+      EntityNamePreference::First => first,
+      EntityNamePreference::Shortest => {
+        names().min_by_key(|name| (name.len(), !is_lowercase(name), *name))
+      }
+      EntityNamePreference::Html4 => names()
+        .find(|name| HTML4_ENTITIES.binary_search(name).is_ok())
+        .or(first),
+      EntityNamePreference::Lowercase => names().find(|name| is_lowercase(name)).or(first),
+      EntityNamePreference::Custom {
+        names: map,
+        fallback,
+      } => match map.get(&ch) {
+        Some(&name) if names().any(|entity| entity == name) => Some(name),
+        _ => fallback.find_name_in(ch, entity_set),
+      },
+    }
+  }
+}
+
+/// Similar to the `encode_char` method, but only the named entities in the entity set are used,
+/// the character without a named entity in the set is encoded into a numeric entity if the encode type allows.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+///
+/// let char_entity = encode_char_in(&'\u{2026}', &EncodeType::NamedOrHex, &EntitySet::Html4);
+/// assert_eq!(char_entity.unwrap().to_string(), "&hellip;");
+/// let char_entity = encode_char_in(&'\u{2026}', &EncodeType::NamedOrHex, &EntitySet::Html32);
+/// assert_eq!(char_entity.unwrap().to_string(), "&#x2026;");
+/// assert!(encode_char_in(&'\u{2026}', &EncodeType::Named, &EntitySet::Html32).is_none());
+/// ```
+pub fn encode_char_in(
+  ch: &char,
+  encode_type: &EncodeType,
+  entity_set: &EntitySet,
+) -> Option<CharEntity> {
+  let name = EntityNamePreference::First.find_name_in(*ch, entity_set);
+  format_entity(name, *ch as u32, encode_type, &NumericFormat::default()).map(
+    |(entity_type, entity_data)| CharEntity {
+      entity_type,
+      entity_data,
+    },
+  )
+}
+
 /// Encode character into html entity.
 ///
 /// # Examples
@@ -1046,43 +1994,52 @@ impl Entity {
 /// assert_eq!(char_entity.unwrap().to_string(), "&#x3c;");
 /// ```
 pub fn encode_char(ch: &char, encode_type: &EncodeType) -> Option<CharEntity> {
-  let encode_type = *encode_type as u8;
+  encode_char_with_format(ch, encode_type, &NumericFormat::default())
+}
+
+/// Similar to the `encode_char` method, but the numeric entities are written in the numeric format.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+///
+/// let format = NumericFormat {
+///   uppercase_digits: true,
+///   ..Default::default()
+/// };
+/// let char_entity = encode_char_with_format(&'\u{4e16}', &EncodeType::Hex, &format).unwrap();
+/// assert_eq!(char_entity.to_string(), "&#x4E16;");
+/// // the hex entity is shorter than the decimal entity `&#1048573;`
+/// let char_entity = encode_char_with_format(&'\u{ffffd}', &EncodeType::Shortest, &format).unwrap();
+/// assert_eq!(char_entity.entity_type(), &EntityType::Hex);
+/// // the named entity `&apos;` is longer than the decimal entity
+/// let char_entity = encode_char_with_format(&'\'', &EncodeType::Shortest, &format).unwrap();
+/// assert_eq!(char_entity.to_string(), "&#39;");
+/// ```
+pub fn encode_char_with_format(
+  ch: &char,
+  encode_type: &EncodeType,
+  format: &NumericFormat,
+) -> Option<CharEntity> {
   let char_code = *ch as u32;
-  // encode to named
-  if (encode_type & (EncodeType::Named as u8)) > 0 {
-    // find the named entity from the ENTITIES
-    if let Ok(mut index) = ENTITIES.binary_search_by_key(&char_code, |&(_, code)| code) {
-      // make sure the entity is the first one, short and lowercase
-      while index > 0 {
-        let prev_index = index - 1;
-        if ENTITIES[prev_index].1 != char_code {
-          break;
-        }
-        index = prev_index;
-      }
-      let &(entity, _) = &ENTITIES[index];
-      return Some(CharEntity {
-        entity_type: EntityType::Named,
-        entity_data: Cow::from(entity),
-      });
-    }
-  }
-  // encode to hex
-  if (encode_type & (EncodeType::Hex as u8)) > 0 {
-    return Some(CharEntity {
-      entity_type: EntityType::Hex,
-      entity_data: Cow::Owned(format!("{:x}", char_code).into_bytes()),
-    });
-  }
-  // encode to decimal
-  if (encode_type & (EncodeType::Decimal as u8)) > 0 {
-    return Some(CharEntity {
-      entity_type: EntityType::Decimal,
-      entity_data: Cow::Owned(char_code.to_string().into_bytes()),
-    });
-  }
-  // no need to encode or failure
-  None
+  format_entity(find_named_entity(char_code), char_code, encode_type, format).map(
+    |(entity_type, entity_data)| CharEntity {
+      entity_type,
+      entity_data,
+    },
+  )
+}
+
+// choose the entity of the character like the `encode_char` method, but the digits are not formatted
+pub(crate) fn choose_char_entity(ch: char, encode_type: &EncodeType) -> Option<EntityChoice> {
+  let char_code = ch as u32;
+  choose_entity(
+    find_named_entity(char_code),
+    char_code,
+    encode_type,
+    &NumericFormat::default(),
+  )
 }
 
 /// Encode characters in the utf-8 bytes into html entities according to the specified encoding format and specified encoding character set.
@@ -1185,10 +2142,24 @@ pub fn encode_with<'a>(
   encode_type: &EncodeType,
   filter_fn: impl Fn(&char, &EncodeType) -> EncodeFilterReturnData,
 ) -> EncodedData<'a> {
+  EncodedData {
+    inner_bytes: Cow::from(content),
+    entities: encode_entities(content, encode_type, |ch, encode_type, _| {
+      filter_fn(ch, encode_type)
+    }),
+  }
+}
+
+// the entities of the characters encoded by the filter, which also receives the byte index after the character
+fn encode_entities(
+  content: &[Byte],
+  encode_type: &EncodeType,
+  filter_fn: impl Fn(&char, &EncodeType, usize) -> EncodeFilterReturnData,
+) -> Vec<(CodeRange, CharEntity)> {
   let mut entities: Vec<(CodeRange, CharEntity)> = vec![];
   let _ = loop_utf8_bytes(content, |result, (start_index, index)| match result {
     Utf8ParsedData::Correct(ch) => {
-      let (need_encode, maybe_entity) = filter_fn(&ch, encode_type);
+      let (need_encode, maybe_entity) = filter_fn(&ch, encode_type, index + 1);
       if need_encode {
         if let Some((entity_type, entity_data)) = maybe_entity {
           entities.push((
@@ -1206,10 +2177,7 @@ pub fn encode_with<'a>(
     }
     _ => Ok(()),
   });
-  EncodedData {
-    inner_bytes: Cow::from(content),
-    entities,
-  }
+  entities
 }
 
 /// Similar to the `encode_with` method, but directly writes the byte data into the last parameter passed in.
@@ -1235,10 +2203,25 @@ pub fn encode_with_to(
   encode_type: &EncodeType,
   filter_fn: impl Fn(&char, &EncodeType) -> EncodeFilterReturnData,
   data: &mut ByteList,
+) {
+  encode_entities_to(
+    content,
+    encode_type,
+    |ch, encode_type, _| filter_fn(ch, encode_type),
+    data,
+  );
+}
+
+// write the content with the characters encoded by the filter, which also receives the byte index after the character
+fn encode_entities_to(
+  content: &[Byte],
+  encode_type: &EncodeType,
+  filter_fn: impl Fn(&char, &EncodeType, usize) -> EncodeFilterReturnData,
+  data: &mut ByteList,
 ) {
   let _ = loop_utf8_bytes(content, |result, (start_index, end_index)| match result {
     Utf8ParsedData::Correct(ch) => {
-      let (need_encode, maybe_entity) = filter_fn(&ch, encode_type);
+      let (need_encode, maybe_entity) = filter_fn(&ch, encode_type, end_index + 1);
       if need_encode {
         if let Some((entity_type, entity_data)) = maybe_entity {
           let entity = CharEntity {
@@ -1262,6 +2245,555 @@ pub fn encode_with_to(
   });
 }
 
+/// Encode the characters in the utf-8 bytes into html entities by the encode options.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::AnyhowResult;
+/// # fn main() -> AnyhowResult<()> {
+/// let options = EncodeOptions {
+///   encode_type: EncodeType::Named,
+///   charset: CharacterSet::SpecialChars,
+///   xml_version: Some(XmlVersion::V1_0),
+///   ..Default::default()
+/// };
+/// // the html entity `&copy;` is not defined in xml
+/// let encoded_data = encode_with_options("<a title='\u{a9}'>\r\n</a>".as_bytes(), &options)?;
+/// assert_eq!(encoded_data.to_string()?, "&lt;a title=&apos;\u{a9}&apos;&gt;&#xd;\n&lt;/a&gt;");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The `keep_references` option keeps the character references in the content, so the encoded content is not changed by encoding again.
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::AnyhowResult;
+/// # fn main() -> AnyhowResult<()> {
+/// let options = EncodeOptions {
+///   charset: CharacterSet::Html,
+///   keep_references: true,
+///   ..Default::default()
+/// };
+/// let encoded_data = encode_with_options(b"&amp; & &foo; &#60<", &options)?;
+/// assert_eq!(encoded_data.to_string()?, "&amp; &amp; &amp;foo; &amp;#60&lt;");
+/// let encoded_bytes = encoded_data.to_bytes();
+/// assert_eq!(encode_with_options(&encoded_bytes, &options)?.to_bytes(), encoded_bytes);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The `forbidden_code_points` option handles the code points forbidden in html documents before the characters are encoded.
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::AnyhowResult;
+/// # fn main() -> AnyhowResult<()> {
+/// let content = "<b>\u{1}\r\n\u{fffe}</b>".as_bytes();
+/// let encode = |policy| {
+///   let options = EncodeOptions {
+///     forbidden_code_points: policy,
+///     ..Default::default()
+///   };
+///   encode_with_options(content, &options)
+/// };
+/// assert_eq!(encode(ForbiddenCodePointPolicy::Encode)?.to_string()?, "&lt;b&gt;&#x1;\r\n&#xfffe;&lt;/b&gt;");
+/// assert_eq!(encode(ForbiddenCodePointPolicy::Replace)?.to_string()?, "&lt;b&gt;\u{fffd}\r\n\u{fffd}&lt;/b&gt;");
+/// assert_eq!(encode(ForbiddenCodePointPolicy::Strip)?.to_string()?, "&lt;b&gt;\r\n&lt;/b&gt;");
+/// assert_eq!(
+///   encode(ForbiddenCodePointPolicy::Error).err(),
+///   Some(EncodeError::ForbiddenCodePoint(3, '\u{1}'))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_with_options<'a>(
+  content: &'a [Byte],
+  options: &EncodeOptions,
+) -> Result<EncodedData<'a>, EncodeError> {
+  let content = options.forbidden_code_points.apply(content)?;
+  let references = options.kept_references(&content);
+  let entities = encode_entities(&content, &options.encode_type, |ch, _, next_index| {
+    options.filter_at(&content, &references, ch, next_index)
+  });
+  Ok(EncodedData {
+    inner_bytes: content,
+    entities,
+  })
+}
+
+/// Similar to the `encode_with_options` method, but directly writes the byte data into the last parameter passed in, nothing is written if an error is returned.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::ByteList;
+///
+/// let options = EncodeOptions {
+///   encode_type: EncodeType::NamedOrDecimal,
+///   charset: CharacterSet::Html,
+///   xml_version: Some(XmlVersion::V1_1),
+///   ..Default::default()
+/// };
+/// let mut data: ByteList = vec![];
+/// encode_with_options_to("<\u{1}>".as_bytes(), &options, &mut data).unwrap();
+/// assert_eq!(data, b"&lt;&#1;&gt;");
+/// // the lone CR is forbidden, but CR LF is allowed
+/// let options = EncodeOptions {
+///   encode_type: EncodeType::Decimal,
+///   forbidden_code_points: ForbiddenCodePointPolicy::Encode,
+///   ..Default::default()
+/// };
+/// let mut data: ByteList = vec![];
+/// encode_with_options_to(b"a\rb\r\n\x7f", &options, &mut data).unwrap();
+/// assert_eq!(data, b"a&#13;b\r\n&#127;");
+/// ```
+pub fn encode_with_options_to(
+  content: &[Byte],
+  options: &EncodeOptions,
+  data: &mut ByteList,
+) -> Result<(), EncodeError> {
+  let content = options.forbidden_code_points.apply(content)?;
+  let references = options.kept_references(&content);
+  encode_entities_to(
+    &content,
+    &options.encode_type,
+    |ch, _, next_index| options.filter_at(&content, &references, ch, next_index),
+    data,
+  );
+  Ok(())
+}
+
+/// Encode the characters in the utf-8 bytes which can break out of the escape context.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::AnyhowResult;
+/// # fn main() -> AnyhowResult<()> {
+/// let content = b"<a href=\"?a=1&b=2\">";
+/// assert_eq!(encode_for(&EscapeContext::Text, content, &EncodeType::Named).to_string()?, "&lt;a href=\"?a=1&amp;b=2\"&gt;");
+/// assert_eq!(encode_for(&EscapeContext::DoubleQuotedAttribute, content, &EncodeType::Named).to_string()?, "<a href=&quot;?a=1&amp;b=2&quot;>");
+/// assert_eq!(encode_for(&EscapeContext::UnquotedAttribute, b"a b", &EncodeType::Named).to_string()?, "a&#x20;b");
+/// assert_eq!(encode_for(&EscapeContext::Comment, b"--><script>", &EncodeType::Named).to_string()?, "--&gt;&lt;script&gt;");
+/// assert_eq!(encode_for(&EscapeContext::Srcdoc, b"<p>\"&</p>", &EncodeType::Named).to_string()?, "&amp;lt;p&amp;gt;&quot;&amp;amp;&amp;lt;/p&amp;gt;");
+/// assert_eq!(encode_for(&EscapeContext::Markdown, b"# *a*", &EncodeType::Decimal).to_string()?, "&#35; &#42;a&#42;");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_for<'a>(
+  context: &EscapeContext,
+  content: &'a [Byte],
+  encode_type: &EncodeType,
+) -> EncodedData<'a> {
+  encode_for_with_format(context, content, encode_type, &NumericFormat::default())
+}
+
+/// Similar to the `encode_for` method, but the numeric entities are written in the numeric format.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::AnyhowResult;
+/// # fn main() -> AnyhowResult<()> {
+/// let format = NumericFormat {
+///   min_width: 4,
+///   ..Default::default()
+/// };
+/// let encoded_data = encode_for_with_format(&EscapeContext::Markdown, b"# *a*", &EncodeType::Decimal, &format);
+/// assert_eq!(encoded_data.to_string()?, "&#0035; &#0042;a&#0042;");
+/// # Ok(())
+/// # }
+/// ```
+pub fn encode_for_with_format<'a>(
+  context: &EscapeContext,
+  content: &'a [Byte],
+  encode_type: &EncodeType,
+  format: &NumericFormat,
+) -> EncodedData<'a> {
+  EncodedData {
+    inner_bytes: Cow::from(content),
+    entities: encode_entities(content, encode_type, |ch, encode_type, next_index| {
+      context.filter_at(content, ch, encode_type, format, next_index)
+    }),
+  }
+}
+
+/// Similar to the `encode_for` method, but directly writes the byte data into the last parameter passed in.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::ByteList;
+///
+/// let mut data: ByteList = vec![];
+/// encode_for_to(&EscapeContext::SingleQuotedAttribute, b"it's <b>", &EncodeType::Named, &mut data);
+/// assert_eq!(data, b"it&apos;s <b>");
+/// ```
+pub fn encode_for_to(
+  context: &EscapeContext,
+  content: &[Byte],
+  encode_type: &EncodeType,
+  data: &mut ByteList,
+) {
+  encode_for_with_format_to(
+    context,
+    content,
+    encode_type,
+    &NumericFormat::default(),
+    data,
+  );
+}
+
+/// Similar to the `encode_for_with_format` method, but directly writes the byte data into the last parameter passed in.
+pub fn encode_for_with_format_to(
+  context: &EscapeContext,
+  content: &[Byte],
+  encode_type: &EncodeType,
+  format: &NumericFormat,
+  data: &mut ByteList,
+) {
+  encode_entities_to(
+    content,
+    encode_type,
+    |ch, encode_type, next_index| context.filter_at(content, ch, encode_type, format, next_index),
+    data,
+  );
+}
+
+/// QuotePolicy: how the attribute value is quoted by the `write_attribute` method.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub enum QuotePolicy {
+  /// always use the double quotes
+  Double,
+  /// always use the single quotes
+  Single,
+  /// use the quotes which need less escaping, the double quotes are preferred
+  #[default]
+  Auto,
+  /// don't use quotes if the value can be unquoted safely, otherwise same as `Auto`
+  Minimal,
+  /// never use quotes, return an error if the value can't be unquoted safely
+  Unquoted,
+}
+
+/// AttributeQuote: the quotes used by the `write_attribute` method.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AttributeQuote {
+  /// the double quotes
+  Double,
+  /// the single quotes
+  Single,
+  /// no quotes
+  None,
+}
+
+// check if the attribute name is valid by the html syntax
+fn is_valid_attribute_name(name: &[Byte]) -> bool {
+  match std::str::from_utf8(name) {
+    Ok(name) => {
+      !name.is_empty()
+        && name.chars().all(|ch| {
+          !(ch.is_control()
+            || matches!(ch, ' ' | '"' | '\'' | '>' | '/' | '=')
+            || is_noncharacter(ch as u32))
+        })
+    }
+    Err(_) => false,
+  }
+}
+
+// the value can be unquoted when it's not empty, and only the '&' needs to be encoded
+fn can_unquote_value(value: &[Byte]) -> bool {
+  !value.is_empty()
+    && value
+      .iter()
+      .all(|&byte| byte == b'&' || !EscapeContext::UnquotedAttribute.contains(&(byte as char)))
+}
+
+/// Write the attribute `name="value"` into the data, the quotes are chosen by the policy,
+/// and only the characters which can break out of the chosen quotes are encoded.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::ByteList;
+///
+/// let mut data: ByteList = vec![];
+/// let quote = write_attribute(b"title", b"say \"hi\"", &QuotePolicy::Auto, &mut data);
+/// assert_eq!(quote, Ok(AttributeQuote::Single));
+/// assert_eq!(data, b"title='say \"hi\"'");
+///
+/// let mut data: ByteList = vec![];
+/// // the '=' can't be unquoted
+/// let quote = write_attribute(b"href", b"?a=1&b=2", &QuotePolicy::Minimal, &mut data);
+/// assert_eq!(quote, Ok(AttributeQuote::Double));
+/// assert_eq!(data, b"href=\"?a=1&amp;b=2\"");
+///
+/// let mut data: ByteList = vec![];
+/// assert_eq!(write_attribute(b"class", b"a b", &QuotePolicy::Unquoted, &mut data), Err(AttributeError::UnsafeUnquotedValue));
+/// assert_eq!(write_attribute(b"on click", b"", &QuotePolicy::Auto, &mut data), Err(AttributeError::InvalidName));
+/// assert!(data.is_empty());
+/// ```
+pub fn write_attribute(
+  name: &[Byte],
+  value: &[Byte],
+  policy: &QuotePolicy,
+  data: &mut ByteList,
+) -> Result<AttributeQuote, AttributeError> {
+  if !is_valid_attribute_name(name) {
+    return Err(AttributeError::InvalidName);
+  }
+  let auto_quote = || {
+    let double_quotes = value.iter().filter(|&&byte| byte == b'"').count();
+    let single_quotes = value.iter().filter(|&&byte| byte == b'\'').count();
+    if double_quotes > single_quotes {
+      AttributeQuote::Single
+    } else {
+      AttributeQuote::Double
+    }
+  };
+  let quote = match policy {
+    QuotePolicy::Double => AttributeQuote::Double,
+    QuotePolicy::Single => AttributeQuote::Single,
+    QuotePolicy::Auto => auto_quote(),
+    QuotePolicy::Minimal => {
+      if can_unquote_value(value) {
+        AttributeQuote::None
+      } else {
+        auto_quote()
+      }
+    }
+    QuotePolicy::Unquoted => {
+      if !can_unquote_value(value) {
+        return Err(AttributeError::UnsafeUnquotedValue);
+      }
+      AttributeQuote::None
+    }
+  };
+  let (context, quote_byte) = match quote {
+    AttributeQuote::Double => (EscapeContext::DoubleQuotedAttribute, Some(b'"')),
+    AttributeQuote::Single => (EscapeContext::SingleQuotedAttribute, Some(b'\'')),
+    AttributeQuote::None => (EscapeContext::UnquotedAttribute, None),
+  };
+  data.extend_from_slice(name);
+  data.push(b'=');
+  data.extend(quote_byte);
+  encode_with_to(
+    value,
+    &EncodeType::Named,
+    |ch, encode_type| context.filter(ch, encode_type),
+    data,
+  );
+  data.extend(quote_byte);
+  Ok(quote)
+}
+
+/// LineBreakMode: how the `text_to_html` method converts the line breaks.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub enum LineBreakMode {
+  /// every line break is converted into `<br>`
+  #[default]
+  Br,
+  /// the lines separated by blank lines are wrapped in `<p>` as paragraphs, and the line breaks in a paragraph are converted into `<br>`
+  Paragraph,
+}
+
+/// TextToHtmlOptions: the options used by the `text_to_html` and `text_to_html_to` methods.
+#[derive(Clone)]
+pub struct TextToHtmlOptions {
+  /// the encode type of the escaped characters and the no-break spaces
+  pub encode_type: EncodeType,
+  /// the characters to be escaped, the spaces and tabs are always converted by the method
+  pub charset: CharacterSet,
+  /// how the line breaks are converted
+  pub line_breaks: LineBreakMode,
+  /// the number of spaces a tab is expanded into, 4 by default
+  pub tab_width: usize,
+}
+
+impl Default for TextToHtmlOptions {
+  fn default() -> Self {
+    TextToHtmlOptions {
+      encode_type: EncodeType::Named,
+      charset: CharacterSet::Html,
+      line_breaks: LineBreakMode::Br,
+      tab_width: 4,
+    }
+  }
+}
+
+// split the text into lines by LF, CR LF and the lone CR
+fn split_lines(content: &[Byte]) -> Vec<&[Byte]> {
+  let mut lines = vec![];
+  let mut start_index = 0;
+  let mut index = 0;
+  while index < content.len() {
+    match content[index] {
+      b'\n' => lines.push(&content[start_index..index]),
+      b'\r' => {
+        lines.push(&content[start_index..index]);
+        if content.get(index + 1) == Some(&b'\n') {
+          index += 1;
+        }
+      }
+      _ => {
+        index += 1;
+        continue;
+      }
+    }
+    index += 1;
+    start_index = index;
+  }
+  lines.push(&content[start_index..]);
+  lines
+}
+
+// write the escaped line, the runs of spaces and tabs alternate no-break spaces with spaces so they're not collapsed,
+// a run starts with a no-break space at the start of the line and ends with one before the line break
+fn write_text_line(
+  line: &[Byte],
+  options: &TextToHtmlOptions,
+  nbsp: &CharEntity,
+  data: &mut ByteList,
+) {
+  let is_space = |byte: &Byte| matches!(byte, b' ' | b'\t');
+  let mut index = 0;
+  while index < line.len() {
+    let len = line[index..]
+      .iter()
+      .take_while(|byte| is_space(byte))
+      .count();
+    if len == 0 {
+      let len = line[index..]
+        .iter()
+        .take_while(|byte| !is_space(byte))
+        .count();
+      encode_to(
+        &line[index..index + len],
+        &options.encode_type,
+        &options.charset,
+        data,
+      );
+      index += len;
+      continue;
+    }
+    let width: usize = line[index..index + len]
+      .iter()
+      .map(|&byte| if byte == b'\t' { options.tab_width } else { 1 })
+      .sum();
+    let at_line_start = index == 0;
+    let at_line_end = index + len == line.len();
+    let mut is_nbsp = at_line_start || (at_line_end && width % 2 == 1);
+    for n in 0..width {
+      if is_nbsp || (at_line_end && n == width - 1) {
+        nbsp.write_bytes(data);
+      } else {
+        data.push(b' ');
+      }
+      is_nbsp = !is_nbsp;
+    }
+    index += len;
+  }
+}
+
+/// Convert the plain text into html, the characters in the character set are escaped by the `encode_to` method,
+/// the line breaks (LF, CR LF and the lone CR) are converted by the line break mode, and the spaces and tabs are kept by the no-break spaces.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+///
+/// let text = "Hi <all>,\r\n  indented\tline\n\nbye";
+/// assert_eq!(
+///   text_to_html(text.as_bytes(), &Default::default()),
+///   b"Hi &lt;all&gt;,<br>\n&nbsp; indented &nbsp; &nbsp;line<br>\n<br>\nbye"
+/// );
+/// let options = TextToHtmlOptions {
+///   encode_type: EncodeType::Decimal,
+///   line_breaks: LineBreakMode::Paragraph,
+///   ..Default::default()
+/// };
+/// assert_eq!(
+///   text_to_html(text.as_bytes(), &options),
+///   b"<p>Hi &#60;all&#62;,<br>\n&#160; indented &#160; &#160;line</p>\n<p>bye</p>"
+/// );
+/// ```
+pub fn text_to_html(content: &[Byte], options: &TextToHtmlOptions) -> ByteList {
+  let mut data = Vec::with_capacity(content.len());
+  text_to_html_to(content, options, &mut data);
+  data
+}
+
+/// Similar to the `text_to_html` method, but directly writes the byte data into the last parameter passed in.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::ByteList;
+///
+/// let options = TextToHtmlOptions {
+///   charset: CharacterSet::SpecialChars,
+///   line_breaks: LineBreakMode::Paragraph,
+///   ..Default::default()
+/// };
+/// let mut data: ByteList = vec![];
+/// text_to_html_to(b"\n'a'\n \n\n\"b\"\n", &options, &mut data);
+/// assert_eq!(data, b"<p>&apos;a&apos;</p>\n<p>&quot;b&quot;</p>");
+/// ```
+pub fn text_to_html_to(content: &[Byte], options: &TextToHtmlOptions, data: &mut ByteList) {
+  // the encode types can always encode the no-break space
+  let nbsp = encode_char(&'\u{a0}', &options.encode_type).unwrap();
+  let lines = split_lines(content);
+  match options.line_breaks {
+    LineBreakMode::Br => {
+      for (index, line) in lines.iter().enumerate() {
+        if index > 0 {
+          data.extend_from_slice(b"<br>\n");
+        }
+        write_text_line(line, options, &nbsp, data);
+      }
+    }
+    LineBreakMode::Paragraph => {
+      let mut in_paragraph = false;
+      let mut has_paragraph = false;
+      for line in lines {
+        // the blank lines end the paragraph
+        if line.iter().all(|byte| matches!(byte, b' ' | b'\t')) {
+          if in_paragraph {
+            data.extend_from_slice(b"</p>");
+            in_paragraph = false;
+          }
+          continue;
+        }
+        if in_paragraph {
+          data.extend_from_slice(b"<br>\n");
+        } else {
+          if has_paragraph {
+            data.push(b'\n');
+          }
+          data.extend_from_slice(b"<p>");
+          in_paragraph = true;
+          has_paragraph = true;
+        }
+        write_text_line(line, options, &nbsp, data);
+      }
+      if in_paragraph {
+        data.extend_from_slice(b"</p>");
+      }
+    }
+  }
+}
+
 /// Encode a list of characters using a filter function.
 ///
 /// # Examples
@@ -1271,7 +2803,7 @@ pub fn encode_with_to(
 /// use std::borrow::Cow;
 ///
 /// let chars = String::from("<div class='header'></div>").chars().collect::<Vec<char>>();
-/// let character_set = CharacterSet::HtmlAndNonASCII;
+/// let character_set = CharacterSet::HtmlAndNonAscii;
 /// let encoded_chars = encode_chars_with(&chars, |ch|{
 ///   if character_set.contains(ch) || *ch == '\''{
 ///      return Some(&EncodeType::Named);
@@ -1312,6 +2844,56 @@ pub fn encode_chars_with(
   Cow::Borrowed(chars)
 }
 
+/// Encode a list of characters by the encode options, the `forbidden_code_points` policy is applied like the `encode_with_options` method.
+///
+/// The index of the `EncodeError::ForbiddenCodePoint` error is the index in the characters.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+///
+/// let chars = String::from("<b>\u{1}</b>").chars().collect::<Vec<char>>();
+/// let options = EncodeOptions {
+///   forbidden_code_points: ForbiddenCodePointPolicy::Encode,
+///   ..Default::default()
+/// };
+/// let encoded_chars = encode_chars_with_options(&chars, &options).unwrap();
+/// assert_eq!(encoded_chars.iter().collect::<String>(), "&lt;b&gt;&#x1;&lt;/b&gt;");
+/// let options = EncodeOptions {
+///   forbidden_code_points: ForbiddenCodePointPolicy::Error,
+///   ..Default::default()
+/// };
+/// assert_eq!(
+///   encode_chars_with_options(&chars, &options).err(),
+///   Some(EncodeError::ForbiddenCodePoint(3, '\u{1}'))
+/// );
+/// ```
+pub fn encode_chars_with_options<'a>(
+  chars: &'a [char],
+  options: &EncodeOptions,
+) -> Result<Cow<'a, [char]>, EncodeError> {
+  let content = chars_to_utf8_bytes(chars);
+  let encoded_data = encode_with_options(&content, options).map_err(|error| match error {
+    // the byte index is converted into the index in the characters
+    EncodeError::ForbiddenCodePoint(index, ch) => {
+      let char_index = content[..index]
+        .iter()
+        .filter(|&&byte| byte & 0xc0 != 0x80)
+        .count();
+      EncodeError::ForbiddenCodePoint(char_index, ch)
+    }
+  })?;
+  if encoded_data.entities.is_empty() && matches!(encoded_data.inner_bytes, Cow::Borrowed(_)) {
+    return Ok(Cow::Borrowed(chars));
+  }
+  // the encoded bytes are always utf-8
+  let encoded_bytes = encoded_data.to_bytes();
+  Ok(Cow::Owned(
+    String::from_utf8_lossy(&encoded_bytes).chars().collect(),
+  ))
+}
+
 /// Decode the html entities in the character list.
 ///
 /// # Examples
@@ -1344,14 +2926,14 @@ pub fn decode_chars(chars: &[char]) -> Cow<'_, [char]> {
           if start_index != idx {
             let bytes = tr_chars_to_utf8_bytes(&chars[start_index..idx]);
             if let Some(bytes) = bytes {
-              if let Ok(decode_char) = Entity::decode(&bytes) {
+              if let Ok(decoded_chars) = Entity::decode(&bytes) {
                 // find at least one entity
                 // append the entity's prev chars
                 if start_index > 1 {
                   data.extend_from_slice(&chars[..start_index - 1]);
                 }
-                // append entity character
-                data.push(decode_char);
+                // append entity characters
+                data.extend_from_slice(&decoded_chars);
                 // append the left character
                 let next_idx = idx + 1;
                 if next_idx != chars.len() {
@@ -1415,9 +2997,9 @@ pub fn decode_chars_to(chars: &[char], data: &mut Vec<char>) {
           if start_index != idx {
             let bytes = tr_chars_to_utf8_bytes(&chars[start_index..idx]);
             if let Some(bytes) = bytes {
-              if let Ok(decode_char) = Entity::decode(&bytes) {
-                // find the
-                data.push(decode_char);
+              if let Ok(decoded_chars) = Entity::decode(&bytes) {
+                // find the entity
+                data.extend_from_slice(&decoded_chars);
                 is_in_entity = false;
                 continue;
               }
@@ -1478,60 +3060,8 @@ pub fn decode_chars_to(chars: &[char], data: &mut Vec<char>) {
 /// # Ok(())
 /// # }
 /// ```
-/// ```
 pub fn decode(content: &[Byte]) -> DecodedData<'_> {
-  let mut entities: Vec<(CodeRange, (char, ByteList))> = vec![];
-  let mut errors: Vec<(CodeRange, anyhow::Error)> = vec![];
-  let mut is_in_entity = false;
-  let mut start_index: usize = 0;
-  for (idx, byte) in content.iter().enumerate() {
-    if !is_in_entity {
-      // not in entity
-      if *byte == b'&' {
-        is_in_entity = true;
-        start_index = idx + 1;
-      }
-    } else {
-      // in entity
-      match *byte {
-        b';' => {
-          // end of the entity, ignore '&;'
-          if start_index != idx {
-            let decode_result = Entity::decode(&content[start_index..idx]);
-            match decode_result {
-              Ok(decode_char) => {
-                entities.push((
-                  start_index - 1..=idx,
-                  (decode_char, char_to_utf8_bytes(decode_char)),
-                ));
-              }
-              Err(err) => {
-                errors.push((start_index - 1..=idx, err));
-              }
-            };
-          }
-          is_in_entity = false;
-        }
-        b'&' => {
-          // always reset entity start index
-          errors.push((
-            start_index - 1..=start_index - 1,
-            HtmlEntityError::Decode(String::from("Unencoded html entity characters '&'.")).into(),
-          ));
-          start_index = idx + 1;
-        }
-        _ => {
-          // entity bytes
-        }
-      }
-    }
-  }
-  // wrong entity at the end
-  DecodedData {
-    inner_bytes: Cow::from(content),
-    entities,
-    errors,
-  }
+  decode_with(content, &DecodeOptions::default())
 }
 
 /// Similar to the `decode` method, but directly writes the byte data into the last parameter passed in.
@@ -1549,45 +3079,436 @@ pub fn decode(content: &[Byte]) -> DecodedData<'_> {
 /// assert_eq!(data, b"<div class='header'></div>");
 /// ```
 pub fn decode_to(content: &[Byte], data: &mut Vec<Byte>) {
-  let mut is_in_entity = false;
-  let mut start_index: usize = 0;
-  for (idx, byte) in content.iter().enumerate() {
-    if !is_in_entity {
-      // not in entity
-      if *byte == b'&' {
-        is_in_entity = true;
-        start_index = idx + 1;
-      } else {
-        data.push(*byte);
+  decode_with_to(content, &DecodeOptions::default(), data);
+}
+
+/// DecodeMode: how the character references are recognized when decoding.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub enum DecodeMode {
+  /// Only decode the character references end with ';',
+  /// the numeric references with more than 32 significant digits are kept as they are and reported as outside the unicode range.
+  #[default]
+  Strict,
+  /// Decode like the html5 tokenizer, the legacy named references such as `&copy` and the numeric references can omit the ending ';'.
+  Html5,
+  /// Decode like the xml parser, only the predefined entities `&lt;`, `&gt;`, `&amp;`, `&quot;` and `&apos;` are recognized,
+  /// the numeric references must reference a `Char` of the xml version, otherwise they are kept as they are and reported.
+  Xml(XmlVersion),
+}
+
+/// XmlVersion: the version of the xml document.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq, Hash)]
+pub enum XmlVersion {
+  /// xml 1.0
+  #[default]
+  V1_0,
+  /// xml 1.1
+  V1_1,
+}
+
+impl XmlVersion {
+  /// Check if the character matches the `Char` production of the xml version, only these characters can be referenced.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::entity::XmlVersion;
+  ///
+  /// assert!(XmlVersion::V1_0.is_char('\n'));
+  /// assert!(!XmlVersion::V1_0.is_char('\u{1}'));
+  /// assert!(XmlVersion::V1_1.is_char('\u{1}'));
+  /// assert!(!XmlVersion::V1_1.is_char('\u{fffe}'));
+  /// ```
+  pub fn is_char(&self, ch: char) -> bool {
+    match ch as u32 {
+      0x9 | 0xa | 0xd => true,
+      0x1..=0x1f => *self == XmlVersion::V1_1,
+      0x20..=0xd7ff | 0xe000..=0xfffd | 0x10000..=0x10ffff => true,
+      _ => false,
+    }
+  }
+  /// Check if the character can be written literally in the xml document without being changed by the xml parser.
+  /// The characters not matching the `Char` production, the restricted characters of xml 1.1,
+  /// and the line breaks normalized into '\n' such as '\r' can't be written literally.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::entity::XmlVersion;
+  ///
+  /// assert!(XmlVersion::V1_0.is_literal_char('\u{80}'));
+  /// assert!(!XmlVersion::V1_1.is_literal_char('\u{80}'));
+  /// assert!(!XmlVersion::V1_0.is_literal_char('\r'));
+  /// ```
+  pub fn is_literal_char(&self, ch: char) -> bool {
+    if !self.is_char(ch) || ch == '\r' {
+      return false;
+    }
+    match self {
+      XmlVersion::V1_0 => true,
+      XmlVersion::V1_1 => {
+        !matches!(ch as u32, 0x1..=0x8 | 0xb | 0xc | 0xe..=0x1f | 0x7f..=0x9f | 0x2028)
       }
-    } else {
-      // in entity
-      match *byte {
-        b';' => {
-          // end of the entity, ignore '&;'
-          if start_index != idx {
-            if let Ok(decode_char) = Entity::decode(&content[start_index..idx]) {
-              data.extend(char_to_utf8_bytes(decode_char));
-              is_in_entity = false;
-              continue;
+    }
+  }
+}
+
+// the predefined entities of xml
+static XML_ENTITIES: [(Bytes, Chars); 5] = [
+  (b"amp", &['&']),
+  (b"apos", &['\'']),
+  (b"gt", &['>']),
+  (b"lt", &['<']),
+  (b"quot", &['"']),
+];
+
+/// DecodeContext: where the content to be decoded comes from.
+#[derive(Copy, Clone, Default, Debug, PartialEq, Eq)]
+pub enum DecodeContext {
+  /// the text content of the elements
+  #[default]
+  Text,
+  /// the attribute values, in the `DecodeMode::Html5` mode a legacy named reference without the ending ';' is kept as it is when followed by '=' or an alphanumeric character, e.g. `href="?a=1&copy=2"`.
+  Attribute,
+}
+
+/// DecodeOptions: the options used by the `decode_with` and `decode_with_to` methods.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct DecodeOptions {
+  /// the decode mode
+  pub mode: DecodeMode,
+  /// the decode context
+  pub context: DecodeContext,
+  /// only the named entities in the entity set are decoded, the others are reported as unknown, it's ignored in the `DecodeMode::Xml` mode
+  pub entity_set: EntitySet,
+}
+
+// the characters of the character reference, the numeric reference doesn't need an allocation
+pub(crate) enum ReferenceChars {
+  Named(Chars),
+  Numeric(char),
+}
+
+impl ReferenceChars {
+  pub(crate) fn as_slice(&self) -> &[char] {
+    match self {
+      ReferenceChars::Named(chars) => chars,
+      ReferenceChars::Numeric(ch) => std::slice::from_ref(ch),
+    }
+  }
+  pub(crate) fn into_decoded(self) -> DecodedChars {
+    match self {
+      ReferenceChars::Named(chars) => Cow::Borrowed(chars),
+      ReferenceChars::Numeric(ch) => Cow::Owned(vec![ch]),
+    }
+  }
+}
+
+// the character reference found at the '&'
+pub(crate) struct CharacterReference {
+  // the index after the last byte of the reference
+  pub(crate) end_index: usize,
+  pub(crate) chars: ReferenceChars,
+}
+
+#[inline]
+fn push_decode_error(
+  errors: &mut Option<&mut Vec<(CodeRange, DecodeErrorKind)>>,
+  range: CodeRange,
+  kind: DecodeErrorKind,
+) {
+  if let Some(errors) = errors {
+    errors.push((range, kind));
+  }
+}
+
+// parse the character reference begin with the '&' at the start index
+pub(crate) fn parse_reference(
+  content: &[Byte],
+  start_index: usize,
+  options: &DecodeOptions,
+  mut errors: Option<&mut Vec<(CodeRange, DecodeErrorKind)>>,
+) -> Option<CharacterReference> {
+  let total = content.len();
+  let is_html5 = options.mode == DecodeMode::Html5;
+  let xml_version = match options.mode {
+    DecodeMode::Xml(version) => Some(version),
+    _ => None,
+  };
+  let mut index = start_index + 1;
+  let first = content.get(index).copied().unwrap_or_default();
+  if !first.is_ascii_alphanumeric() && first != b'#' {
+    // a bare '&' is not well-formed in xml
+    if xml_version.is_some() {
+      push_decode_error(
+        &mut errors,
+        start_index..=start_index,
+        DecodeErrorKind::AmbiguousAmpersand,
+      );
+    }
+    return None;
+  }
+  if first.is_ascii_alphanumeric() {
+    // named reference
+    while index < total && content[index].is_ascii_alphanumeric() {
+      index += 1;
+    }
+    let has_semicolon = content.get(index) == Some(&b';');
+    if xml_version.is_some() {
+      let name = &content[start_index + 1..index];
+      let xml_entity = XML_ENTITIES.iter().find(|(entity, _)| *entity == name);
+      match xml_entity {
+        Some(&(_, chars)) if has_semicolon => {
+          return Some(CharacterReference {
+            end_index: index + 1,
+            chars: ReferenceChars::Named(chars),
+          });
+        }
+        Some(_) => push_decode_error(
+          &mut errors,
+          start_index..=index - 1,
+          DecodeErrorKind::MissingSemicolonAfterCharacterReference,
+        ),
+        None if has_semicolon => push_decode_error(
+          &mut errors,
+          start_index..=index,
+          DecodeErrorKind::UnknownNamedCharacterReference,
+        ),
+        None => push_decode_error(
+          &mut errors,
+          start_index..=start_index,
+          DecodeErrorKind::AmbiguousAmpersand,
+        ),
+      }
+      return None;
+    }
+    let entity_set = &options.entity_set;
+    let is_all = matches!(entity_set, EntitySet::Html5 | EntitySet::MathMl);
+    let entity_match = ENTITY_TRIE.longest_match_by(
+      &content[start_index + 1..],
+      entity_set.allow_legacy(),
+      |name| is_all || entity_set.contains(name),
+    );
+    if let Some(entity_match) = entity_match {
+      let end_index = start_index + 1 + entity_match.bytes_len();
+      if entity_match.has_semicolon {
+        return Some(CharacterReference {
+          end_index,
+          chars: ReferenceChars::Named(entity_match.chars),
+        });
+      }
+      if is_html5 {
+        // the legacy named reference without the ending ';'
+        if options.context == DecodeContext::Attribute {
+          // for historical reasons, keep the reference in attribute value if followed by '=' or alphanumeric
+          if let Some(&next) = content.get(end_index) {
+            if next == b'=' || next.is_ascii_alphanumeric() {
+              return None;
             }
           }
-          data.extend_from_slice(&content[start_index - 1..=idx]);
-          is_in_entity = false;
-        }
-        b'&' => {
-          // always reset entity start index
-          data.extend_from_slice(&content[start_index - 1..idx]);
-          start_index = idx + 1;
-        }
-        _ => {
-          // entity bytes
         }
+        push_decode_error(
+          &mut errors,
+          start_index..=end_index - 1,
+          DecodeErrorKind::MissingSemicolonAfterCharacterReference,
+        );
+        return Some(CharacterReference {
+          end_index,
+          chars: ReferenceChars::Named(entity_match.chars),
+        });
+      }
+    }
+    // an alphanumeric sequence which is not a character reference
+    if has_semicolon {
+      push_decode_error(
+        &mut errors,
+        start_index..=index,
+        DecodeErrorKind::UnknownNamedCharacterReference,
+      );
+    } else if !is_html5
+      && (entity_match.is_some() || entity_set.contains(&content[start_index + 1..index]))
+    {
+      push_decode_error(
+        &mut errors,
+        start_index..=index - 1,
+        DecodeErrorKind::MissingSemicolonAfterCharacterReference,
+      );
+    }
+    // otherwise the '&' is just a literal ampersand, which is not a parse error
+    return None;
+  }
+  // numeric reference
+  index += 1;
+  let radix = match content.get(index) {
+    // the xml only allows the lowercase 'x'
+    Some(b'x') => {
+      index += 1;
+      16
+    }
+    Some(b'X') if xml_version.is_none() => {
+      index += 1;
+      16
+    }
+    _ => 10,
+  };
+  let digits_index = index;
+  while index < total && (content[index] as char).is_digit(radix) {
+    index += 1;
+  }
+  if digits_index == index {
+    push_decode_error(
+      &mut errors,
+      start_index..=index - 1,
+      DecodeErrorKind::AbsenceOfDigitsInNumericCharacterReference,
+    );
+    return None;
+  }
+  let has_semicolon = content.get(index) == Some(&b';');
+  if !has_semicolon && !is_html5 {
+    push_decode_error(
+      &mut errors,
+      start_index..=index - 1,
+      DecodeErrorKind::MissingSemicolonAfterCharacterReference,
+    );
+    return None;
+  }
+  let end_index = if has_semicolon { index + 1 } else { index };
+  if options.mode == DecodeMode::Strict {
+    let digits = &content[digits_index..index];
+    let zeros = digits.iter().take_while(|&&byte| byte == b'0').count();
+    if digits.len() - zeros > MAX_STRICT_SIGNIFICANT_DIGITS {
+      push_decode_error(
+        &mut errors,
+        start_index..=end_index - 1,
+        DecodeErrorKind::CharacterReferenceOutsideUnicodeRange,
+      );
+      return None;
+    }
+  }
+  if let Some(version) = xml_version {
+    let char_code = parse_char_code(&content[digits_index..index], radix);
+    return match char::from_u32(char_code).filter(|&ch| version.is_char(ch)) {
+      Some(ch) => Some(CharacterReference {
+        end_index,
+        chars: ReferenceChars::Numeric(ch),
+      }),
+      None => {
+        push_decode_error(
+          &mut errors,
+          start_index..=end_index - 1,
+          DecodeErrorKind::InvalidXmlCharacterReference,
+        );
+        None
       }
+    };
+  }
+  let (ch, error) = numbers_to_char(&content[digits_index..index], radix);
+  if !has_semicolon {
+    push_decode_error(
+      &mut errors,
+      start_index..=end_index - 1,
+      DecodeErrorKind::MissingSemicolonAfterCharacterReference,
+    );
+  }
+  if let Some(kind) = error {
+    push_decode_error(&mut errors, start_index..=end_index - 1, kind);
+  }
+  Some(CharacterReference {
+    end_index,
+    chars: ReferenceChars::Numeric(ch),
+  })
+}
+
+// check if the character reference begin with the '&' at the start index may be changed by the bytes after the content
+pub(crate) fn is_incomplete_reference(content: &[Byte], start_index: usize) -> bool {
+  let rest = &content[start_index + 1..];
+  match rest.first() {
+    None => true,
+    Some(b'#') => match rest.get(1) {
+      None => true,
+      Some(b'x' | b'X') => rest[2..].iter().all(Byte::is_ascii_hexdigit),
+      Some(_) => rest[1..].iter().all(Byte::is_ascii_digit),
+    },
+    Some(byte) if byte.is_ascii_alphanumeric() => {
+      // the alphanumeric sequence longer than the entity names can't be changed
+      rest.len() <= ENTITY_TRIE.max_name_len() && rest.iter().all(Byte::is_ascii_alphanumeric)
     }
+    _ => false,
   }
-  if is_in_entity {
-    // add the end non regular entity
-    data.extend_from_slice(&content[start_index - 1..]);
+}
+
+/// Decode html entities in utf-8 bytes with the decode options.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::AnyhowResult;
+/// # fn main() -> AnyhowResult<()> {
+/// let content = b"&copy 2024 a&ampb &lt;";
+/// // the strict mode only decode the entities end with ';'
+/// assert_eq!(decode(content).to_string()?, "&copy 2024 a&ampb <");
+/// // the html5 mode decode the legacy entities like the browsers
+/// let options = DecodeOptions { mode: DecodeMode::Html5, ..Default::default() };
+/// let decoded_data = decode_with(content, &options);
+/// assert_eq!(decoded_data.to_string()?, "\u{a9} 2024 a&b <");
+/// // the missing semicolons are recorded as errors
+/// assert_eq!(decoded_data.get_errors().len(), 2);
+/// // decode an attribute value like the browsers do
+/// let options = DecodeOptions { mode: DecodeMode::Html5, context: DecodeContext::Attribute, ..Default::default() };
+/// assert_eq!(decode_with(b"?a=1&copy=2&copy 3", &options).to_string()?, "?a=1&copy=2\u{a9} 3");
+/// # Ok(())
+/// # }
+/// ```
+pub fn decode_with<'a>(content: &'a [Byte], options: &DecodeOptions) -> DecodedData<'a> {
+  let mut entities: Vec<(CodeRange, (DecodedChars, ByteList))> = vec![];
+  let mut errors: Vec<(CodeRange, DecodeErrorKind)> = vec![];
+  let mut index = 0;
+  while let Some(pos) = content[index..].iter().position(|&byte| byte == b'&') {
+    let start_index = index + pos;
+    if let Some(reference) = parse_reference(content, start_index, options, Some(&mut errors)) {
+      let bytes = chars_to_utf8_bytes(reference.chars.as_slice());
+      entities.push((
+        start_index..=reference.end_index - 1,
+        (reference.chars.into_decoded(), bytes),
+      ));
+      index = reference.end_index;
+    } else {
+      index = start_index + 1;
+    }
+  }
+  DecodedData {
+    inner_bytes: Cow::from(content),
+    entities,
+    errors,
+  }
+}
+
+/// Similar to the `decode_with` method, but directly writes the byte data into the last parameter passed in.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::types::ByteList;
+///
+/// let options = DecodeOptions { mode: DecodeMode::Html5, ..Default::default() };
+/// let mut data: ByteList = vec![];
+/// decode_with_to(b"&lt&#60;&#x3c", &options, &mut data);
+/// assert_eq!(data, b"<<<");
+/// ```
+pub fn decode_with_to(content: &[Byte], options: &DecodeOptions, data: &mut Vec<Byte>) {
+  let mut index = 0;
+  while let Some(pos) = content[index..].iter().position(|&byte| byte == b'&') {
+    let start_index = index + pos;
+    if let Some(reference) = parse_reference(content, start_index, options, None) {
+      data.extend_from_slice(&content[index..start_index]);
+      data.extend(chars_to_utf8_bytes(reference.chars.as_slice()));
+      index = reference.end_index;
+    } else {
+      data.extend_from_slice(&content[index..=start_index]);
+      index = start_index + 1;
+    }
   }
+  data.extend_from_slice(&content[index..]);
 }
@@ -0,0 +1,285 @@
+use crate::{
+  entity::{CharacterSet, DecodeOptions, EncodeType},
+  stream::{StreamCoder, StreamDecoder, StreamEncoder},
+  types::{Byte, ByteList, EncodeFilterReturnData},
+};
+use std::io::{self, ErrorKind, Read, Write};
+
+// the size of the bytes read from the inner reader each time
+const READ_BUFFER_SIZE: usize = 8 * 1024;
+
+/// CodingWriter: write the bytes through a stream coder into the inner writer.
+///
+/// The partial utf-8 characters and character references at the end of each write are kept until the next write,
+/// so the written bytes are always the same as encoding or decoding the whole content.
+/// The `finish` method should be called after all the content is written, otherwise it's called when the writer is dropped and the errors are ignored.
+pub struct CodingWriter<W: Write, C: StreamCoder> {
+  inner: Option<W>,
+  coder: C,
+  // the handled bytes not written into the inner writer yet
+  buffer: ByteList,
+}
+
+/// EncodingWriter: encode the written bytes into html entities.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::{CharacterSet, EncodeType};
+/// use htmlentity::io::EncodingWriter;
+/// use std::io::Write;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut writer = EncodingWriter::new(vec![], EncodeType::Named, CharacterSet::Html);
+/// writer.write_all(b"<p>")?;
+/// writer.write_all(b"&</p>")?;
+/// assert_eq!(writer.finish()?, b"&lt;p&gt;&amp;&lt;/p&gt;");
+/// # Ok(())
+/// # }
+/// ```
+pub type EncodingWriter<W> = CodingWriter<W, StreamEncoder>;
+
+/// DecodingWriter: decode the html entities in the written bytes.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::DecodeOptions;
+/// use htmlentity::io::DecodingWriter;
+/// use std::io::Write;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut writer = DecodingWriter::new(vec![], DecodeOptions::default());
+/// writer.write_all(b"&lt;p&g")?;
+/// writer.write_all(b"t;&amp;&lt;/p&gt;")?;
+/// assert_eq!(writer.finish()?, b"<p>&</p>");
+/// # Ok(())
+/// # }
+/// ```
+pub type DecodingWriter<W> = CodingWriter<W, StreamDecoder>;
+
+impl<W: Write> CodingWriter<W, StreamEncoder> {
+  /// Create an encoding writer which encodes the characters in the character set.
+  pub fn new(inner: W, encode_type: EncodeType, charset: CharacterSet) -> Self {
+    Self::with_coder(inner, StreamEncoder::new(encode_type, charset))
+  }
+  /// Create an encoding writer which encodes the characters by the `filter_fn`, like the `encode_with` method.
+  pub fn with_filter(
+    inner: W,
+    encode_type: EncodeType,
+    filter_fn: impl Fn(&char, &EncodeType) -> EncodeFilterReturnData + 'static,
+  ) -> Self {
+    Self::with_coder(inner, StreamEncoder::with_filter(encode_type, filter_fn))
+  }
+}
+
+impl<W: Write> CodingWriter<W, StreamDecoder> {
+  /// Create a decoding writer with the decode options.
+  pub fn new(inner: W, options: DecodeOptions) -> Self {
+    Self::with_coder(inner, StreamDecoder::new(options))
+  }
+}
+
+impl<W: Write, C: StreamCoder> CodingWriter<W, C> {
+  /// Create a writer with the stream coder.
+  pub fn with_coder(inner: W, coder: C) -> Self {
+    CodingWriter {
+      inner: Some(inner),
+      coder,
+      buffer: vec![],
+    }
+  }
+  /// Get a reference to the inner writer.
+  pub fn get_ref(&self) -> &W {
+    self.inner.as_ref().unwrap()
+  }
+  /// Get a mutable reference to the inner writer.
+  pub fn get_mut(&mut self) -> &mut W {
+    self.inner.as_mut().unwrap()
+  }
+  /// Write the pending bytes of the coder into the inner writer and flush it, the writer can still be written after.
+  pub fn try_finish(&mut self) -> io::Result<()> {
+    self.coder.finish(&mut self.buffer);
+    self.write_buffer()?;
+    self.get_mut().flush()
+  }
+  /// Write the pending bytes of the coder into the inner writer, then return the inner writer.
+  pub fn finish(mut self) -> io::Result<W> {
+    self.try_finish()?;
+    Ok(self.inner.take().unwrap())
+  }
+  // write the handled bytes into the inner writer
+  fn write_buffer(&mut self) -> io::Result<()> {
+    let inner = self.inner.as_mut().unwrap();
+    let mut written = 0;
+    let result = loop {
+      if written == self.buffer.len() {
+        break Ok(());
+      }
+      match inner.write(&self.buffer[written..]) {
+        Ok(0) => {
+          break Err(io::Error::new(
+            ErrorKind::WriteZero,
+            "failed to write the buffered data",
+          ))
+        }
+        Ok(count) => written += count,
+        Err(e) if e.kind() == ErrorKind::Interrupted => {}
+        Err(e) => break Err(e),
+      }
+    };
+    self.buffer.drain(..written);
+    result
+  }
+}
+
+impl<W: Write, C: StreamCoder> Write for CodingWriter<W, C> {
+  fn write(&mut self, buf: &[Byte]) -> io::Result<usize> {
+    // the bytes left by the last failed write
+    self.write_buffer()?;
+    self.coder.feed(buf, &mut self.buffer);
+    // the bytes are consumed by the coder, the error will be returned by the next write or flush
+    let _ = self.write_buffer();
+    Ok(buf.len())
+  }
+  /// Flush the handled bytes into the inner writer, the partial utf-8 character or character reference is still kept,
+  /// so that the result is the same as encoding or decoding the whole content.
+  fn flush(&mut self) -> io::Result<()> {
+    self.write_buffer()?;
+    self.get_mut().flush()
+  }
+}
+
+impl<W: Write, C: StreamCoder> Drop for CodingWriter<W, C> {
+  fn drop(&mut self) {
+    if self.inner.is_some() {
+      let _ = self.try_finish();
+    }
+  }
+}
+
+/// CodingReader: read the bytes from the inner reader through a stream coder.
+///
+/// The partial utf-8 characters and character references at the end of each read of the inner reader are kept until the next read,
+/// so the read bytes are always the same as encoding or decoding the whole content.
+pub struct CodingReader<R: Read, C: StreamCoder> {
+  inner: R,
+  coder: C,
+  input: Box<[Byte]>,
+  // the handled bytes not read yet
+  output: ByteList,
+  position: usize,
+  finished: bool,
+}
+
+/// EncodingReader: encode the bytes read from the inner reader into html entities.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::{CharacterSet, EncodeType};
+/// use htmlentity::io::EncodingReader;
+/// use std::io::Read;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut reader = EncodingReader::new(&b"<p>&</p>"[..], EncodeType::Named, CharacterSet::Html);
+/// let mut content = String::new();
+/// reader.read_to_string(&mut content)?;
+/// assert_eq!(content, "&lt;p&gt;&amp;&lt;/p&gt;");
+/// # Ok(())
+/// # }
+/// ```
+pub type EncodingReader<R> = CodingReader<R, StreamEncoder>;
+
+/// DecodingReader: decode the html entities in the bytes read from the inner reader.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::DecodeOptions;
+/// use htmlentity::io::DecodingReader;
+/// use std::io::Read;
+///
+/// # fn main() -> std::io::Result<()> {
+/// let mut reader = DecodingReader::new(&b"&lt;p&gt;&amp;&lt;/p&gt;"[..], DecodeOptions::default());
+/// let mut content = String::new();
+/// reader.read_to_string(&mut content)?;
+/// assert_eq!(content, "<p>&</p>");
+/// # Ok(())
+/// # }
+/// ```
+pub type DecodingReader<R> = CodingReader<R, StreamDecoder>;
+
+impl<R: Read> CodingReader<R, StreamEncoder> {
+  /// Create an encoding reader which encodes the characters in the character set.
+  pub fn new(inner: R, encode_type: EncodeType, charset: CharacterSet) -> Self {
+    Self::with_coder(inner, StreamEncoder::new(encode_type, charset))
+  }
+  /// Create an encoding reader which encodes the characters by the `filter_fn`, like the `encode_with` method.
+  pub fn with_filter(
+    inner: R,
+    encode_type: EncodeType,
+    filter_fn: impl Fn(&char, &EncodeType) -> EncodeFilterReturnData + 'static,
+  ) -> Self {
+    Self::with_coder(inner, StreamEncoder::with_filter(encode_type, filter_fn))
+  }
+}
+
+impl<R: Read> CodingReader<R, StreamDecoder> {
+  /// Create a decoding reader with the decode options.
+  pub fn new(inner: R, options: DecodeOptions) -> Self {
+    Self::with_coder(inner, StreamDecoder::new(options))
+  }
+}
+
+impl<R: Read, C: StreamCoder> CodingReader<R, C> {
+  /// Create a reader with the stream coder.
+  pub fn with_coder(inner: R, coder: C) -> Self {
+    CodingReader {
+      inner,
+      coder,
+      input: vec![0; READ_BUFFER_SIZE].into_boxed_slice(),
+      output: vec![],
+      position: 0,
+      finished: false,
+    }
+  }
+  /// Get a reference to the inner reader.
+  pub fn get_ref(&self) -> &R {
+    &self.inner
+  }
+  /// Get a mutable reference to the inner reader.
+  pub fn get_mut(&mut self) -> &mut R {
+    &mut self.inner
+  }
+  /// Return the inner reader, the bytes handled but not read are lost.
+  pub fn into_inner(self) -> R {
+    self.inner
+  }
+}
+
+impl<R: Read, C: StreamCoder> Read for CodingReader<R, C> {
+  fn read(&mut self, buf: &mut [Byte]) -> io::Result<usize> {
+    if buf.is_empty() {
+      return Ok(0);
+    }
+    while self.position == self.output.len() {
+      if self.finished {
+        return Ok(0);
+      }
+      self.output.clear();
+      self.position = 0;
+      let count = self.inner.read(&mut self.input)?;
+      if count == 0 {
+        self.coder.finish(&mut self.output);
+        self.finished = true;
+      } else {
+        self.coder.feed(&self.input[..count], &mut self.output);
+      }
+    }
+    let count = buf.len().min(self.output.len() - self.position);
+    buf[..count].copy_from_slice(&self.output[self.position..self.position + count]);
+    self.position += count;
+    Ok(count)
+  }
+}
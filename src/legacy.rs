@@ -0,0 +1,191 @@
+use crate::{
+  legacy_data::{
+    SingleBytePair, ISO_8859_1, ISO_8859_15, ISO_8859_2, ISO_8859_5, ISO_8859_7, SHIFT_JIS,
+    WINDOWS_1250, WINDOWS_1251, WINDOWS_1252,
+  },
+  types::{Byte, ByteList},
+};
+use thiserror::Error;
+
+/// TranscodeError: the errors of transcoding the utf-8 bytes into a legacy encoding, the index is the byte index in the utf-8 bytes.
+#[non_exhaustive]
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TranscodeError {
+  /// The bytes are not valid utf-8.
+  #[error("Invalid utf-8 bytes at {0}.")]
+  InvalidUtf8(usize),
+  /// The character can't be represented in the encoding, it should be encoded into an html entity first.
+  #[error("The character {1:?} at {0} can't be represented in the encoding.")]
+  NotRepresentable(usize, char),
+}
+
+/// LegacyEncoding: the legacy output encodings of the html pages.
+///
+/// The encoders follow the WHATWG encoding standard, except that the `Iso8859_1` can't represent the c1 control characters,
+/// because the browsers decode the ISO-8859-1 pages as windows-1252.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::*;
+/// use htmlentity::legacy::LegacyEncoding;
+///
+/// let encoding = LegacyEncoding::Windows1252;
+/// let content = "caf\u{e9} \u{20ac}5 \u{4e16}";
+/// let mut encoded = vec![];
+/// encode_to(
+///   content.as_bytes(),
+///   &EncodeType::NamedOrHex,
+///   &CharacterSet::NotRepresentableIn(encoding),
+///   &mut encoded,
+/// );
+/// assert_eq!(encoded, "caf\u{e9} \u{20ac}5 &#x4e16;".as_bytes());
+/// // the bytes can be served as a windows-1252 page
+/// let mut data = vec![];
+/// encoding.transcode(&encoded, &mut data).unwrap();
+/// assert_eq!(data, b"caf\xe9 \x805 &#x4e16;");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LegacyEncoding {
+  /// windows-1250, central european
+  Windows1250,
+  /// windows-1251, cyrillic
+  Windows1251,
+  /// windows-1252, western european
+  Windows1252,
+  /// ISO-8859-1, western european
+  Iso8859_1,
+  /// ISO-8859-2, central european
+  Iso8859_2,
+  /// ISO-8859-5, cyrillic
+  Iso8859_5,
+  /// ISO-8859-7, greek
+  Iso8859_7,
+  /// ISO-8859-15, western european with the euro sign
+  Iso8859_15,
+  /// Shift_JIS, japanese, the windows-31j variant used by the browsers
+  ShiftJis,
+}
+
+impl LegacyEncoding {
+  /// The name of the encoding, which can be used by the `charset` of the html pages.
+  pub fn name(&self) -> &'static str {
+    use LegacyEncoding::*;
+    match self {
+      Windows1250 => "windows-1250",
+      Windows1251 => "windows-1251",
+      Windows1252 => "windows-1252",
+      Iso8859_1 => "ISO-8859-1",
+      Iso8859_2 => "ISO-8859-2",
+      Iso8859_5 => "ISO-8859-5",
+      Iso8859_7 => "ISO-8859-7",
+      Iso8859_15 => "ISO-8859-15",
+      ShiftJis => "Shift_JIS",
+    }
+  }
+  /// Check if the character can be represented in the encoding.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::legacy::LegacyEncoding;
+  ///
+  /// assert!(LegacyEncoding::Windows1252.is_representable(&'\u{20ac}'));
+  /// assert!(!LegacyEncoding::Iso8859_1.is_representable(&'\u{20ac}'));
+  /// assert!(LegacyEncoding::ShiftJis.is_representable(&'\u{4e16}'));
+  /// ```
+  pub fn is_representable(&self, ch: &char) -> bool {
+    self.lookup(*ch).is_some()
+  }
+  /// Write the encoded bytes of the character into the data, return false if the character can't be represented.
+  pub fn write_char(&self, ch: &char, data: &mut ByteList) -> bool {
+    match self.lookup(*ch) {
+      Some(bytes) if bytes > 0xff => {
+        data.extend_from_slice(&(bytes as u16).to_be_bytes());
+        true
+      }
+      Some(byte) => {
+        data.push(byte as Byte);
+        true
+      }
+      None => false,
+    }
+  }
+  /// Transcode the utf-8 bytes into the encoding, such as the output of the `encode_to` method with `CharacterSet::NotRepresentableIn`.
+  ///
+  /// Nothing is written into the data if an error is returned.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::legacy::{LegacyEncoding, TranscodeError};
+  ///
+  /// let mut data = vec![];
+  /// LegacyEncoding::ShiftJis.transcode("&lt;\u{4e16}\u{754c}".as_bytes(), &mut data).unwrap();
+  /// assert_eq!(data, b"&lt;\x90\xa2\x8a\x45");
+  /// assert_eq!(
+  ///   LegacyEncoding::Windows1251.transcode("a\u{e9}".as_bytes(), &mut data),
+  ///   Err(TranscodeError::NotRepresentable(1, '\u{e9}'))
+  /// );
+  /// ```
+  pub fn transcode(&self, content: &[Byte], data: &mut ByteList) -> Result<(), TranscodeError> {
+    let content =
+      std::str::from_utf8(content).map_err(|e| TranscodeError::InvalidUtf8(e.valid_up_to()))?;
+    let len = data.len();
+    data.reserve(content.len());
+    for (index, ch) in content.char_indices() {
+      if !self.write_char(&ch, data) {
+        data.truncate(len);
+        return Err(TranscodeError::NotRepresentable(index, ch));
+      }
+    }
+    Ok(())
+  }
+  /// Iterate the characters which can be represented in the encoding.
+  pub fn representable_chars(&self) -> impl Iterator<Item = char> + '_ {
+    let non_ascii: Box<dyn Iterator<Item = u16>> = match self.single_byte_table() {
+      Some(table) => Box::new(table.iter().map(|&(code, _)| code)),
+      None => Box::new(SHIFT_JIS.iter().map(|&(code, _)| code)),
+    };
+    (0..0x80u8)
+      .map(char::from)
+      .chain(non_ascii.filter_map(|code| char::from_u32(code as u32)))
+  }
+  // the table of the single-byte encodings
+  fn single_byte_table(&self) -> Option<&'static [SingleBytePair]> {
+    use LegacyEncoding::*;
+    let table: &[SingleBytePair] = match self {
+      Windows1250 => &WINDOWS_1250,
+      Windows1251 => &WINDOWS_1251,
+      Windows1252 => &WINDOWS_1252,
+      Iso8859_1 => &ISO_8859_1,
+      Iso8859_2 => &ISO_8859_2,
+      Iso8859_5 => &ISO_8859_5,
+      Iso8859_7 => &ISO_8859_7,
+      Iso8859_15 => &ISO_8859_15,
+      ShiftJis => return None,
+    };
+    Some(table)
+  }
+  // the encoded bytes of the character, the value less than 0x100 is a single byte
+  fn lookup(&self, ch: char) -> Option<u32> {
+    let code = ch as u32;
+    if code < 0x80 {
+      return Some(code);
+    }
+    if code > 0xffff {
+      return None;
+    }
+    let code = code as u16;
+    match self.single_byte_table() {
+      Some(table) => table
+        .binary_search_by_key(&code, |&(code, _)| code)
+        .ok()
+        .map(|index| table[index].1 as u32),
+      None => SHIFT_JIS
+        .binary_search_by_key(&code, |&(code, _)| code)
+        .ok()
+        .map(|index| SHIFT_JIS[index].1 as u32),
+    }
+  }
+}
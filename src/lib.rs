@@ -17,7 +17,7 @@
 //! let encoded_data = encode(
 //!     html.as_bytes(),
 //!     &EncodeType::NamedOrHex,
-//!     &CharacterSet::HtmlAndNonASCII
+//!     &CharacterSet::HtmlAndNonAscii
 //! );
 //! // encoded data to bytes
 //! assert_eq!(
@@ -58,9 +58,28 @@
 //! # Ok(())
 //! # }
 //! ```
+/// The custom character sets of the encoder.
+pub mod charset;
 /// The html entities data.
 pub mod data;
+/// The display adapters of the encoder and decoder.
+pub mod display;
 /// The library main module.
 pub mod entity;
+/// The io adapters of the encoder and decoder.
+pub mod io;
+/// The legacy output encodings of the encoder.
+pub mod legacy;
+// the generated legacy encoding tables
+mod legacy_data;
+/// The streaming encoder and decoder.
+pub mod stream;
+/// The trie of the named entities.
+pub mod trie;
 /// The library's types.
 pub mod types;
+/// The unicode property sets of the encoder, the properties can only be looked up with the `unicode-properties` feature.
+pub mod unicode;
+// the generated unicode property tables
+#[cfg(feature = "unicode-properties")]
+mod unicode_data;
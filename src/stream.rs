@@ -0,0 +1,283 @@
+use crate::{
+  entity::{
+    chars_to_utf8_bytes, encode_with_to, incomplete_utf8_len, is_incomplete_reference,
+    parse_reference, utf8_char_len, CharacterSet, DecodeMode, DecodeOptions, EncodeType,
+    MAX_STRICT_SIGNIFICANT_DIGITS,
+  },
+  types::{Byte, ByteList, EncodeFilterReturnData},
+};
+use std::mem;
+
+/// The common methods of the stream encoder and decoder.
+pub trait StreamCoder {
+  /// Handle the chunk and write the result bytes into the data.
+  fn feed(&mut self, chunk: &[Byte], data: &mut ByteList);
+  /// Write the pending bytes into the data, the coder can be reused after finished.
+  fn finish(&mut self, data: &mut ByteList);
+}
+
+type EncodeFilter = Box<dyn Fn(&char, &EncodeType) -> EncodeFilterReturnData>;
+
+// the pending bytes longer than it must be a numeric reference, and will be compressed
+const MAX_PENDING_LEN: usize = 64;
+
+// eight significant digits are enough to be outside the unicode range
+const MAX_SIGNIFICANT_DIGITS: usize = 8;
+
+/// StreamDecoder: decode html entities in the utf-8 bytes which are fed in arbitrary chunks.
+///
+/// The output is always the same as the `decode_with_to` method on the whole content,
+/// only the partial character reference at the end of the fed chunks is buffered.
+/// A long numeric reference is never rescanned, its leading zeros are counted and the pending bytes are bounded.
+/// Only eight significant digits are buffered in the `DecodeMode::Html5` mode, the rest digits are dropped because it's outside the unicode range.
+/// The reference outside the unicode range is kept as is in the `DecodeMode::Xml` mode, so it's written out once it has more than eight significant digits,
+/// and so is the reference with more than 32 significant digits in the `DecodeMode::Strict` mode.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::DecodeOptions;
+/// use htmlentity::stream::StreamDecoder;
+///
+/// let mut decoder = StreamDecoder::new(DecodeOptions::default());
+/// let mut data = vec![];
+/// decoder.feed(b"<p>&am", &mut data);
+/// assert_eq!(data, b"<p>");
+/// decoder.feed(b"p;&#x3", &mut data);
+/// assert_eq!(data, b"<p>&");
+/// decoder.feed(b"c;</p>&lt", &mut data);
+/// decoder.finish(&mut data);
+/// assert_eq!(data, b"<p>&<</p>&lt");
+/// ```
+#[derive(Debug, Default)]
+pub struct StreamDecoder {
+  options: DecodeOptions,
+  // the bytes of the partial character reference, begin with '&'
+  pending: ByteList,
+  // the leading zeros of the numeric reference which are dropped from the pending bytes
+  pending_zeros: usize,
+}
+
+impl StreamDecoder {
+  /// Create a stream decoder with the decode options.
+  pub fn new(options: DecodeOptions) -> Self {
+    StreamDecoder {
+      options,
+      ..Default::default()
+    }
+  }
+  /// Decode the chunk and write the decoded bytes into the data, the partial character reference at the end is kept until the next chunk.
+  pub fn feed(&mut self, chunk: &[Byte], data: &mut Vec<Byte>) {
+    let mut chunk = chunk;
+    while !self.pending.is_empty() {
+      if chunk.is_empty() {
+        return;
+      }
+      // complete the pending reference with the beginning bytes of the chunk
+      let count = chunk.len().min(MAX_PENDING_LEN);
+      let mut buffer = mem::take(&mut self.pending);
+      buffer.extend_from_slice(&chunk[..count]);
+      chunk = &chunk[count..];
+      let index = self.decode_part(&buffer, data, false);
+      buffer.drain(..index);
+      self.pending = buffer;
+      self.compress_pending(data);
+    }
+    let index = self.decode_part(chunk, data, false);
+    self.pending.extend_from_slice(&chunk[index..]);
+    self.compress_pending(data);
+  }
+  /// Write the pending partial character reference into the data, the decoder can be reused after finished.
+  pub fn finish(&mut self, data: &mut Vec<Byte>) {
+    let pending = mem::take(&mut self.pending);
+    if !pending.is_empty() {
+      self.decode_part(&pending, data, true);
+    }
+    self.pending_zeros = 0;
+  }
+  /// Check if there is a pending partial character reference.
+  pub fn has_pending(&self) -> bool {
+    !self.pending.is_empty()
+  }
+  // decode the content, return the start index of the partial reference at the end
+  fn decode_part(&mut self, content: &[Byte], data: &mut Vec<Byte>, is_final: bool) -> usize {
+    let mut index = 0;
+    while let Some(pos) = content[index..].iter().position(|&byte| byte == b'&') {
+      let start_index = index + pos;
+      if !is_final && is_incomplete_reference(content, start_index) {
+        data.extend_from_slice(&content[index..start_index]);
+        return start_index;
+      }
+      // the dropped zeros only belong to the pending reference at the beginning
+      let zeros = if start_index == 0 {
+        mem::take(&mut self.pending_zeros)
+      } else {
+        0
+      };
+      if let Some(reference) = parse_reference(content, start_index, &self.options, None) {
+        data.extend_from_slice(&content[index..start_index]);
+        data.extend(chars_to_utf8_bytes(reference.chars.as_slice()));
+        index = reference.end_index;
+      } else {
+        data.extend_from_slice(&content[index..=start_index]);
+        index = start_index + 1;
+        if zeros > 0 {
+          // restore the dropped zeros after the prefix '#' or '#x'
+          let prefix_end = numeric_prefix_len(content);
+          data.extend_from_slice(&content[index..prefix_end]);
+          data.resize(data.len() + zeros, b'0');
+          index = prefix_end;
+        }
+      }
+    }
+    data.extend_from_slice(&content[index..]);
+    content.len()
+  }
+  // only the numeric references can be longer than the entity names,
+  // drop the leading zeros of the digits to keep the pending bytes short
+  fn compress_pending(&mut self, data: &mut Vec<Byte>) {
+    if self.pending.len() <= MAX_PENDING_LEN {
+      return;
+    }
+    let prefix_end = numeric_prefix_len(&self.pending);
+    let digits = &self.pending[prefix_end..];
+    // keep at least one digit
+    let zeros = digits[..digits.len() - 1]
+      .iter()
+      .take_while(|&&byte| byte == b'0')
+      .count();
+    self.pending.drain(prefix_end..prefix_end + zeros);
+    self.pending_zeros += zeros;
+    let digits_len = self.pending.len() - prefix_end;
+    let is_kept = match self.options.mode {
+      // the numeric references are always decoded in the html5 mode, so the overflow digits are never output
+      DecodeMode::Html5 => {
+        self
+          .pending
+          .truncate(prefix_end + digits_len.min(MAX_SIGNIFICANT_DIGITS));
+        false
+      }
+      // the reference outside the unicode range is never decoded in the xml mode
+      DecodeMode::Xml(_) => digits_len > MAX_SIGNIFICANT_DIGITS,
+      DecodeMode::Strict => digits_len > MAX_STRICT_SIGNIFICANT_DIGITS,
+    };
+    if is_kept {
+      // the reference is written out as is, and the following digits are just text
+      data.extend_from_slice(&self.pending[..prefix_end]);
+      data.resize(data.len() + mem::take(&mut self.pending_zeros), b'0');
+      data.extend_from_slice(&self.pending[prefix_end..]);
+      self.pending.clear();
+    }
+  }
+}
+
+// the length of '&#' or '&#x'
+#[inline]
+fn numeric_prefix_len(content: &[Byte]) -> usize {
+  if matches!(content.get(2), Some(b'x' | b'X')) {
+    3
+  } else {
+    2
+  }
+}
+
+/// StreamEncoder: encode the characters into html entities in the utf-8 bytes which are fed in arbitrary chunks.
+///
+/// The output is always the same as the `encode_to` or `encode_with_to` method on the whole content,
+/// the utf-8 bytes of a character split across the chunks are buffered until the character is complete.
+///
+/// # Examples
+///
+/// ```
+/// use htmlentity::entity::{CharacterSet, EncodeType};
+/// use htmlentity::stream::StreamEncoder;
+///
+/// let mut encoder = StreamEncoder::new(EncodeType::NamedOrHex, CharacterSet::HtmlAndNonAscii);
+/// let mut data = vec![];
+/// let content = "<p>世界</p>".as_bytes();
+/// encoder.feed(&content[..4], &mut data);
+/// assert_eq!(data, b"&lt;p&gt;");
+/// encoder.feed(&content[4..], &mut data);
+/// encoder.finish(&mut data);
+/// assert_eq!(data, b"&lt;p&gt;&#x4e16;&#x754c;&lt;/p&gt;");
+/// ```
+pub struct StreamEncoder {
+  encode_type: EncodeType,
+  filter_fn: EncodeFilter,
+  // the leading bytes of the incomplete character
+  pending: ByteList,
+}
+
+impl StreamEncoder {
+  /// Create a stream encoder which encodes the characters in the character set.
+  pub fn new(encode_type: EncodeType, charset: CharacterSet) -> Self {
+    Self::with_filter(encode_type, move |ch, encode_type| {
+      charset.filter(ch, encode_type)
+    })
+  }
+  /// Create a stream encoder which encodes the characters by the `filter_fn`, like the `encode_with` method.
+  pub fn with_filter(
+    encode_type: EncodeType,
+    filter_fn: impl Fn(&char, &EncodeType) -> EncodeFilterReturnData + 'static,
+  ) -> Self {
+    StreamEncoder {
+      encode_type,
+      filter_fn: Box::new(filter_fn),
+      pending: Vec::with_capacity(4),
+    }
+  }
+  /// Encode the chunk and write the encoded bytes into the data, the incomplete character at the end is kept until the next chunk.
+  pub fn feed(&mut self, chunk: &[Byte], data: &mut ByteList) {
+    let mut chunk = chunk;
+    if !self.pending.is_empty() {
+      // complete the pending character with the continuation bytes
+      let char_len = utf8_char_len(self.pending[0]);
+      let count = chunk
+        .iter()
+        .take(char_len - self.pending.len())
+        .take_while(|&&byte| (byte >> 6) == 0b10)
+        .count();
+      self.pending.extend_from_slice(&chunk[..count]);
+      chunk = &chunk[count..];
+      if self.pending.len() < char_len && chunk.is_empty() {
+        return;
+      }
+      let pending = mem::take(&mut self.pending);
+      self.encode_part(&pending, data);
+    }
+    let complete_len = chunk.len() - incomplete_utf8_len(chunk);
+    self.encode_part(&chunk[..complete_len], data);
+    self.pending.extend_from_slice(&chunk[complete_len..]);
+  }
+  /// Write the pending incomplete character into the data as is, the encoder can be reused after finished.
+  pub fn finish(&mut self, data: &mut ByteList) {
+    let pending = mem::take(&mut self.pending);
+    self.encode_part(&pending, data);
+  }
+  /// Check if there is a pending incomplete character.
+  pub fn has_pending(&self) -> bool {
+    !self.pending.is_empty()
+  }
+  #[inline]
+  fn encode_part(&self, content: &[Byte], data: &mut ByteList) {
+    encode_with_to(content, &self.encode_type, &self.filter_fn, data);
+  }
+}
+
+impl StreamCoder for StreamDecoder {
+  fn feed(&mut self, chunk: &[Byte], data: &mut ByteList) {
+    StreamDecoder::feed(self, chunk, data);
+  }
+  fn finish(&mut self, data: &mut ByteList) {
+    StreamDecoder::finish(self, data);
+  }
+}
+
+impl StreamCoder for StreamEncoder {
+  fn feed(&mut self, chunk: &[Byte], data: &mut ByteList) {
+    StreamEncoder::feed(self, chunk, data);
+  }
+  fn finish(&mut self, data: &mut ByteList) {
+    StreamEncoder::finish(self, data);
+  }
+}
@@ -0,0 +1,229 @@
+use crate::{
+  data::{EntityCharPair, LEGACY_ENTITIES, LETTER_ORDERED_ENTITIES},
+  types::{Byte, Bytes, Chars},
+};
+use lazy_static::lazy_static;
+
+lazy_static! {
+  /// The trie built from all the named entities.
+  pub static ref ENTITY_TRIE: EntityTrie = EntityTrie::new(&LETTER_ORDERED_ENTITIES, &LEGACY_ENTITIES);
+}
+
+// the entity index of the node that is not the end of an entity
+const NO_ENTITY: u16 = u16::MAX;
+
+#[derive(Debug, Clone, Copy)]
+struct TrieNode {
+  // the children of the node are the edges[first_child..last_child]
+  first_child: u32,
+  last_child: u32,
+  // the index of the entity ended at the node
+  entity: u16,
+  // the entity can be decoded without the ending ';'
+  legacy: bool,
+}
+
+/// EntityMatch: the named entity matched by the `EntityTrie::longest_match` method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EntityMatch {
+  /// the entity name, doesn't contain the ending ';'
+  pub name: Bytes,
+  /// the characters of the entity
+  pub chars: Chars,
+  /// if the entity name is followed by a ';'
+  pub has_semicolon: bool,
+}
+
+impl EntityMatch {
+  /// The count of the matched bytes, include the ending ';' if has.
+  pub fn bytes_len(&self) -> usize {
+    self.name.len() + self.has_semicolon as usize
+  }
+}
+
+/// EntityTrie: a trie of the named entities, the lookups are O(name length).
+#[derive(Debug)]
+pub struct EntityTrie {
+  nodes: Vec<TrieNode>,
+  // the edges of the nodes, sorted by the byte for each node
+  edges: Vec<(Byte, u32)>,
+  entities: &'static [EntityCharPair],
+  max_name_len: usize,
+}
+
+impl EntityTrie {
+  /// Build the trie from the entities ordered by letters, and mark the legacy entities which can omit the ending ';'.
+  pub fn new(entities: &'static [EntityCharPair], legacy_entities: &[Bytes]) -> Self {
+    let mut children: Vec<Vec<(Byte, u32)>> = vec![vec![]];
+    let mut node_entities: Vec<u16> = vec![NO_ENTITY];
+    let mut insert = |name: &[Byte]| -> usize {
+      let mut node = 0;
+      for &byte in name {
+        node = match children[node].iter().find(|&&(edge, _)| edge == byte) {
+          Some(&(_, child)) => child as usize,
+          None => {
+            let child = children.len();
+            children[node].push((byte, child as u32));
+            children.push(vec![]);
+            node_entities.push(NO_ENTITY);
+            child
+          }
+        };
+      }
+      node
+    };
+    let mut legacy_nodes = vec![];
+    for name in legacy_entities {
+      legacy_nodes.push(insert(name));
+    }
+    let mut entity_nodes = vec![];
+    for (index, (name, _)) in entities.iter().enumerate() {
+      entity_nodes.push((insert(name), index as u16));
+    }
+    for (node, index) in entity_nodes {
+      node_entities[node] = index;
+    }
+    // flatten the children into edges
+    let mut nodes = Vec::with_capacity(children.len());
+    let mut edges = Vec::with_capacity(children.len());
+    for (mut node_children, entity) in children.into_iter().zip(node_entities) {
+      node_children.sort_unstable();
+      let first_child = edges.len() as u32;
+      edges.extend(node_children);
+      nodes.push(TrieNode {
+        first_child,
+        last_child: edges.len() as u32,
+        entity,
+        legacy: false,
+      });
+    }
+    for node in legacy_nodes {
+      nodes[node].legacy = true;
+    }
+    EntityTrie {
+      nodes,
+      edges,
+      entities,
+      max_name_len: entities
+        .iter()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0),
+    }
+  }
+  /// The length of the longest entity name.
+  pub fn max_name_len(&self) -> usize {
+    self.max_name_len
+  }
+  // the child node of the byte
+  #[inline]
+  fn child(&self, node: usize, byte: Byte) -> Option<usize> {
+    let TrieNode {
+      first_child,
+      last_child,
+      ..
+    } = self.nodes[node];
+    let edges = &self.edges[first_child as usize..last_child as usize];
+    edges
+      .binary_search_by_key(&byte, |&(edge, _)| edge)
+      .ok()
+      .map(|index| edges[index].1 as usize)
+  }
+  #[inline]
+  fn entity(&self, node: usize) -> Option<&'static EntityCharPair> {
+    match self.nodes[node].entity {
+      NO_ENTITY => None,
+      index => Some(&self.entities[index as usize]),
+    }
+  }
+  /// Find the characters of the entity name, the name doesn't contain the beginning '&' and the ending ';'.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::trie::ENTITY_TRIE;
+  ///
+  /// assert_eq!(ENTITY_TRIE.get(b"lt"), Some(&['<'][..]));
+  /// assert_eq!(ENTITY_TRIE.get(b"fjlig"), Some(&['f', 'j'][..]));
+  /// assert_eq!(ENTITY_TRIE.get(b"foo"), None);
+  /// ```
+  pub fn get(&self, name: &[Byte]) -> Option<Chars> {
+    let mut node = 0;
+    for &byte in name {
+      node = self.child(node, byte)?;
+    }
+    self.entity(node).map(|&(_, chars)| chars)
+  }
+  /// Find the longest entity at the beginning of the bytes like the html5 tokenizer does,
+  /// an entity matches when it's followed by a ';', or it's a legacy entity which can omit the ending ';'.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::trie::ENTITY_TRIE;
+  ///
+  /// let entity_match = ENTITY_TRIE.longest_match(b"notin;").unwrap();
+  /// assert_eq!(entity_match.name, b"notin");
+  /// assert!(entity_match.has_semicolon);
+  /// // only the legacy entity 'not' can omit the ending ';'
+  /// let entity_match = ENTITY_TRIE.longest_match(b"notit;").unwrap();
+  /// assert_eq!(entity_match.name, b"not");
+  /// assert_eq!(entity_match.chars, &['\u{ac}']);
+  /// assert!(!entity_match.has_semicolon);
+  /// assert_eq!(ENTITY_TRIE.longest_match(b"hellip"), None);
+  /// ```
+  pub fn longest_match(&self, bytes: &[Byte]) -> Option<EntityMatch> {
+    self.longest_match_by(bytes, true, |_| true)
+  }
+  /// Similar to the `longest_match` method, but only the entity names accepted by the `accept` method can be matched,
+  /// and the legacy entities can omit the ending ';' only if `allow_legacy` is true.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::trie::ENTITY_TRIE;
+  ///
+  /// let entity_match = ENTITY_TRIE.longest_match_by(b"notin;", true, |name| name != b"notin").unwrap();
+  /// assert_eq!(entity_match.name, b"not");
+  /// assert_eq!(ENTITY_TRIE.longest_match_by(b"notin;", false, |name| name != b"notin"), None);
+  /// ```
+  pub fn longest_match_by(
+    &self,
+    bytes: &[Byte],
+    allow_legacy: bool,
+    accept: impl Fn(Bytes) -> bool,
+  ) -> Option<EntityMatch> {
+    let mut node = 0;
+    let mut result = None;
+    for &byte in bytes {
+      if byte == b';' {
+        if let Some(&(name, chars)) = self.entity(node) {
+          if accept(name) {
+            return Some(EntityMatch {
+              name,
+              chars,
+              has_semicolon: true,
+            });
+          }
+        }
+        break;
+      }
+      match self.child(node, byte) {
+        Some(child) => node = child,
+        None => break,
+      }
+      if allow_legacy && self.nodes[node].legacy {
+        if let Some(&(name, chars)) = self.entity(node) {
+          if accept(name) {
+            result = Some(EntityMatch {
+              name,
+              chars,
+              has_semicolon: false,
+            });
+          }
+        }
+      }
+    }
+    result
+  }
+}
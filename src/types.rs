@@ -5,8 +5,10 @@ use crate::entity::EntityType;
 pub type Byte = u8;
 pub type ByteList = Vec<Byte>;
 pub type Bytes = &'static [Byte];
+pub type Chars = &'static [char];
+pub type DecodedChars = Cow<'static, [char]>;
 pub type EntityCharBytes = HashMap<char, Bytes>;
-pub type BytesCharEntity = HashMap<Bytes, char>;
+pub type BytesCharEntity = HashMap<Bytes, Chars>;
 pub type AnyhowResult<T> = anyhow::Result<T>;
 pub type StringResult = AnyhowResult<String>;
 pub type CharListResult = AnyhowResult<Vec<char>>;
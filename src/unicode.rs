@@ -0,0 +1,176 @@
+#[cfg(feature = "unicode-properties")]
+use crate::unicode_data::{
+  PropertyAlias, PropertyTable, BINARY_PROPERTIES, BINARY_PROPERTY_ALIASES, GENERAL_CATEGORIES,
+  GENERAL_CATEGORY_ALIASES, SCRIPTS, SCRIPT_ALIASES,
+};
+#[cfg(feature = "unicode-properties")]
+use std::str::FromStr;
+use thiserror::Error;
+
+/// UnicodePropertyError: the errors of parsing a unicode property.
+#[non_exhaustive]
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnicodePropertyError {
+  /// The property name before '=' is not `General_Category` or `Script`, or their aliases `gc` and `sc`.
+  #[error("Unknown unicode property name.")]
+  UnknownName,
+  /// The property value is not a general category, a script or a supported binary property.
+  #[error("Unknown unicode property value.")]
+  UnknownValue,
+}
+
+/// UnicodePropertyKind: the kind of the unicode property.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum UnicodePropertyKind {
+  /// the general category, e.g. `Format` (`Cf`) or the group category `Letter` (`L`)
+  GeneralCategory,
+  /// the script, e.g. `Han` (`Hani`)
+  Script,
+  /// the binary property, e.g. `Emoji_Presentation` (`EPres`)
+  Binary,
+}
+
+/// UnicodeProperty: the characters with a unicode property, which can be used by `CharacterSet::UnicodeProperty`.
+///
+/// The supported properties are the general categories, the scripts, and the binary properties
+/// `Bidi_Control`, `Default_Ignorable_Code_Point`, `Emoji`, `Emoji_Component`, `Emoji_Modifier`, `Emoji_Modifier_Base`,
+/// `Emoji_Presentation`, `Extended_Pictographic`, `Join_Control`, `Noncharacter_Code_Point`, `Pattern_White_Space`,
+/// `Regional_Indicator`, `Variation_Selector` and `White_Space` of the unicode version 16.0.0.
+/// The names are matched loosely: the letter case, spaces, '_' and '-' are ignored, and the short aliases can be used.
+/// The type is always available, but the properties can only be looked up with the `unicode-properties` feature which bundles the tables.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "unicode-properties")]
+/// # {
+/// use htmlentity::entity::*;
+/// use htmlentity::unicode::UnicodeProperty;
+///
+/// let format = UnicodeProperty::general_category("Cf").unwrap();
+/// assert_eq!(format.name(), "Format");
+/// assert!(format.contains(&'\u{200b}'));
+/// let charset = CharacterSet::UnicodeProperty(format);
+/// let encoded_data = encode("a\u{200b}b".as_bytes(), &EncodeType::NamedOrHex, &charset);
+/// assert_eq!(encoded_data.to_string().unwrap(), "a&ZeroWidthSpace;b");
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnicodeProperty {
+  kind: UnicodePropertyKind,
+  name: &'static str,
+  tables: &'static [&'static [(u32, u32)]],
+}
+
+#[cfg(feature = "unicode-properties")]
+impl UnicodeProperty {
+  /// Find the general category by the name or the alias, e.g. `Format`, `Cf`, `Letter` or `L`, `Unassigned` or `Cn`.
+  pub fn general_category(name: &str) -> Option<Self> {
+    Self::find(
+      UnicodePropertyKind::GeneralCategory,
+      name,
+      &GENERAL_CATEGORY_ALIASES,
+      &GENERAL_CATEGORIES,
+    )
+  }
+  /// Find the script by the name or the alias, e.g. `Han` or `Hani`.
+  pub fn script(name: &str) -> Option<Self> {
+    Self::find(UnicodePropertyKind::Script, name, &SCRIPT_ALIASES, &SCRIPTS)
+  }
+  /// Find the binary property by the name or the alias, e.g. `Emoji_Presentation` or `EPres`.
+  pub fn binary(name: &str) -> Option<Self> {
+    Self::find(
+      UnicodePropertyKind::Binary,
+      name,
+      &BINARY_PROPERTY_ALIASES,
+      &BINARY_PROPERTIES,
+    )
+  }
+  /// Parse the property like the `\p{..}` of the regular expressions, it's `name=value`, or a general category, a script or a binary property in order.
+  ///
+  /// # Examples
+  ///
+  /// ```
+  /// use htmlentity::unicode::{UnicodeProperty, UnicodePropertyError, UnicodePropertyKind};
+  ///
+  /// let han = UnicodeProperty::parse("sc=Hani").unwrap();
+  /// assert_eq!((han.kind(), han.name()), (UnicodePropertyKind::Script, "Han"));
+  /// assert!(han.contains(&'\u{4e16}'));
+  /// let emoji: UnicodeProperty = "Emoji_Presentation".parse().unwrap();
+  /// assert!(emoji.contains(&'\u{1f600}') && !emoji.contains(&'#'));
+  /// assert_eq!(UnicodeProperty::parse("Block=Basic_Latin"), Err(UnicodePropertyError::UnknownName));
+  /// assert_eq!(UnicodeProperty::parse("Klingon"), Err(UnicodePropertyError::UnknownValue));
+  /// ```
+  pub fn parse(property: &str) -> Result<Self, UnicodePropertyError> {
+    let result = match property.split_once('=') {
+      Some((name, value)) => match normalize(name).as_str() {
+        "gc" | "generalcategory" => Self::general_category(value),
+        "sc" | "script" => Self::script(value),
+        _ => return Err(UnicodePropertyError::UnknownName),
+      },
+      None => Self::general_category(property)
+        .or_else(|| Self::script(property))
+        .or_else(|| Self::binary(property)),
+    };
+    result.ok_or(UnicodePropertyError::UnknownValue)
+  }
+  // find the property by the loose matched alias
+  fn find(
+    kind: UnicodePropertyKind,
+    name: &str,
+    aliases: &[PropertyAlias],
+    tables: &[PropertyTable],
+  ) -> Option<Self> {
+    let alias = normalize(name);
+    let index = aliases
+      .binary_search_by(|&(key, _)| key.cmp(alias.as_str()))
+      .ok()?;
+    let canonical = aliases[index].1;
+    let index = tables
+      .binary_search_by(|&(key, _)| key.cmp(canonical))
+      .ok()?;
+    let (name, tables) = tables[index];
+    Some(UnicodeProperty { kind, name, tables })
+  }
+}
+
+impl UnicodeProperty {
+  /// The kind of the property.
+  pub fn kind(&self) -> UnicodePropertyKind {
+    self.kind
+  }
+  /// The canonical name of the property value, e.g. `Format` for `Cf`.
+  pub fn name(&self) -> &'static str {
+    self.name
+  }
+  /// Check if the character has the property.
+  pub fn contains(&self, ch: &char) -> bool {
+    let code = *ch as u32;
+    self.tables.iter().any(|ranges| {
+      let index = ranges.partition_point(|&(_, end)| end < code);
+      ranges.get(index).is_some_and(|&(start, _)| start <= code)
+    })
+  }
+  /// Iterate the code point ranges of the property, the ranges of a group category are not merged.
+  pub(crate) fn code_ranges(&self) -> impl Iterator<Item = (u32, u32)> {
+    self.tables.iter().flat_map(|ranges| ranges.iter().copied())
+  }
+}
+
+#[cfg(feature = "unicode-properties")]
+impl FromStr for UnicodeProperty {
+  type Err = UnicodePropertyError;
+  fn from_str(property: &str) -> Result<Self, Self::Err> {
+    UnicodeProperty::parse(property)
+  }
+}
+
+// the loose matching of the symbolic names, ignore the letter case, spaces, '_' and '-'
+#[cfg(feature = "unicode-properties")]
+fn normalize(name: &str) -> String {
+  name
+    .chars()
+    .filter(|ch| !matches!(ch, ' ' | '_' | '-'))
+    .map(|ch| ch.to_ascii_lowercase())
+    .collect()
+}
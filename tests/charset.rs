@@ -0,0 +1,146 @@
+use htmlentity::charset::{CharSet, CharSetParseError};
+use htmlentity::entity::{encode, CharacterSet, EncodeType, ICodedDataTrait};
+use htmlentity::types::AnyhowResult;
+
+#[test]
+fn test_char_set_parse() -> AnyhowResult<()> {
+  let set: CharSet = "[<>&\"'`=\\u0080-\\uFFFF]".parse()?;
+  for ch in [
+    '<', '>', '&', '"', '\'', '`', '=', '\u{80}', '\u{4e16}', '\u{ffff}',
+  ] {
+    assert!(set.contains(&ch), "{:?}", ch);
+  }
+  for ch in ['a', ' ', '\u{7f}', '\u{10000}', '\u{1f600}'] {
+    assert!(!set.contains(&ch), "{:?}", ch);
+  }
+  assert_eq!(
+    set.ranges().collect::<Vec<_>>(),
+    vec![
+      '"'..='"',
+      '&'..='\'',
+      '<'..='>',
+      '`'..='`',
+      '\u{80}'..='\u{d7ff}',
+      '\u{e000}'..='\u{ffff}'
+    ]
+  );
+  // escapes
+  let set = CharSet::parse(r"[\t\n\x41\u{1F600}\]\\\-\^]")?;
+  assert_eq!(
+    set,
+    ['\t', '\n', 'A', '\u{1f600}', ']', '\\', '-', '^']
+      .iter()
+      .copied()
+      .collect()
+  );
+  // the literal '-' at the start and the end
+  assert_eq!(CharSet::parse("[-a-]")?, "-a".chars().collect());
+  // negation
+  let set = CharSet::parse("[^\\x00-\\x7f]")?;
+  assert!(!set.contains(&'a') && set.contains(&'\u{80}') && set.contains(&char::MAX));
+  assert_eq!(CharSet::parse("[^]")?, CharSet::from(&CharacterSet::All));
+  assert!(CharSet::parse("[]")?.is_empty());
+  // errors
+  let errors = [
+    ("abc", CharSetParseError::MissingBracket),
+    ("[abc", CharSetParseError::MissingBracket),
+    ("[a]b", CharSetParseError::MissingBracket),
+    (r"[a\]", CharSetParseError::MissingBracket),
+    (r"[\u12]", CharSetParseError::InvalidEscape(1)),
+    (r"[\u{}]", CharSetParseError::InvalidEscape(1)),
+    (r"[a\u{1234567}]", CharSetParseError::InvalidEscape(2)),
+    (r"[\d]", CharSetParseError::InvalidEscape(1)),
+    (r"[\uD800]", CharSetParseError::InvalidCodePoint(1)),
+    (r"[\u{110000}]", CharSetParseError::InvalidCodePoint(1)),
+    ("[ab-a]", CharSetParseError::InvalidRange(2)),
+  ];
+  for (class, error) in errors {
+    assert_eq!(CharSet::parse(class), Err(error), "{}", class);
+  }
+  Ok(())
+}
+
+#[test]
+fn test_char_set_algebra() {
+  let special_chars = CharSet::from(&CharacterSet::SpecialChars);
+  let html = CharSet::from(&CharacterSet::Html);
+  let non_ascii = CharSet::from(&CharacterSet::NonLatin1);
+  assert_eq!(
+    special_chars.difference(&html),
+    "'\"".chars().collect::<CharSet>()
+  );
+  assert_eq!(special_chars.intersection(&html), html);
+  assert_eq!(
+    html.union(&non_ascii),
+    CharSet::from(&CharacterSet::HtmlAndNonLatin1)
+  );
+  assert!(html.intersection(&non_ascii).is_empty());
+  assert_eq!(html.complement().complement(), html);
+  assert_eq!(
+    html.union(&html.complement()),
+    CharSet::from(&CharacterSet::All)
+  );
+  // the sets agree with the presets
+  let presets = vec![
+    CharacterSet::All,
+    CharacterSet::NonLatin1,
+    CharacterSet::Html,
+    CharacterSet::SpecialChars,
+    CharacterSet::HtmlAndNonLatin1,
+    CharacterSet::SpecialCharsAndNonLatin1,
+    CharacterSet::NonAscii,
+    CharacterSet::HtmlAndNonAscii,
+    CharacterSet::SpecialCharsAndNonAscii,
+  ];
+  for preset in presets {
+    let set = CharSet::from(&preset);
+    for code in (0..0x300).chain(0xd7f0..0xe010) {
+      if let Some(ch) = char::from_u32(code) {
+        assert_eq!(set.contains(&ch), preset.contains(&ch));
+      }
+    }
+  }
+  // the ascii bitmap agrees with the ranges
+  let mut set = CharSet::new();
+  set.insert_range('0'..='9');
+  set.insert('\u{7f}');
+  set.insert_range('\u{7e}'..='\u{81}');
+  assert_eq!(
+    set.ranges().collect::<Vec<_>>(),
+    vec!['0'..='9', '\u{7e}'..='\u{81}']
+  );
+  for code in 0..0x100 {
+    let ch = char::from_u32(code).unwrap();
+    assert_eq!(
+      set.contains(&ch),
+      ch.is_ascii_digit() || (0x7e..=0x81).contains(&code)
+    );
+  }
+}
+
+#[test]
+fn test_encode_custom_charset() -> AnyhowResult<()> {
+  let charset = CharacterSet::Custom(CharSet::parse("[<>&`=]")?);
+  assert!(charset.contains(&'`') && !charset.contains(&'"'));
+  assert_eq!(
+    encode(b"<a b=`c`>\"", &EncodeType::NamedOrHex, &charset).to_string()?,
+    "&lt;a b&equals;&grave;c&grave;&gt;\""
+  );
+  let charset: CharacterSet = CharSet::from(&CharacterSet::SpecialChars)
+    .union(&CharSet::from('\u{80}'..='\u{ff}'))
+    .into();
+  assert_eq!(
+    encode("'\u{a9}\u{4e16}".as_bytes(), &EncodeType::Decimal, &charset).to_string()?,
+    "&#39;&#169;\u{4e16}"
+  );
+  Ok(())
+}
+
+#[test]
+fn test_unicode_property_variant() {
+  // the variant is compiled without the `unicode-properties` feature, so the matches don't depend on it
+  let is_unicode_property =
+    |charset: &CharacterSet| matches!(charset, CharacterSet::UnicodeProperty(_));
+  assert!(!is_unicode_property(&CharacterSet::Html));
+  assert!(!is_unicode_property(&CharacterSet::Custom(CharSet::new())));
+}
@@ -0,0 +1,62 @@
+use htmlentity::display::{escape, Decoded, Escaped};
+use htmlentity::entity::{
+  decode_with, encode, CharacterSet, DecodeMode, DecodeOptions, EncodeType, ICodedDataTrait,
+};
+use htmlentity::types::AnyhowResult;
+
+const CONTENTS: [&str; 6] = [
+  "",
+  "plain text",
+  "<p class=\"a\">'\u{4e16}\u{754c}' & \u{1f600}</p>",
+  "&lt;p&gt;&#x4e16;&#30028;&amp&notin;&notit;&#0;&#x110000&lt",
+  "\u{a0}\u{a9}\u{2242}\u{338}",
+  "a\u{200b}b\u{202e}<\u{feff}",
+];
+
+#[test]
+fn test_escaped() -> AnyhowResult<()> {
+  let settings = || {
+    vec![
+      (EncodeType::Named, CharacterSet::SpecialChars),
+      (EncodeType::Named, CharacterSet::NonAscii),
+      (EncodeType::NamedOrHex, CharacterSet::HtmlAndNonAscii),
+      (
+        EncodeType::NamedOrDecimal,
+        CharacterSet::SpecialCharsAndNonLatin1,
+      ),
+      (EncodeType::Hex, CharacterSet::All),
+      (EncodeType::Shortest, CharacterSet::SpecialCharsAndNonAscii),
+      (EncodeType::Named, CharacterSet::HtmlAndInvisible),
+      (EncodeType::Shortest, CharacterSet::Invisible),
+    ]
+  };
+  for content in CONTENTS {
+    for (encode_type, charset) in settings() {
+      let expected = encode(content.as_bytes(), &encode_type, &charset).to_string()?;
+      assert_eq!(
+        Escaped::new(content, encode_type, charset).to_string(),
+        expected
+      );
+    }
+  }
+  assert_eq!(
+    format!("<p title=\"{}\">", escape("\"a\" & 'b'")),
+    "<p title=\"&quot;a&quot; &amp; &apos;b&apos;\">"
+  );
+  Ok(())
+}
+
+#[test]
+fn test_decoded() -> AnyhowResult<()> {
+  for content in CONTENTS {
+    for mode in [DecodeMode::Strict, DecodeMode::Html5] {
+      let options = DecodeOptions {
+        mode,
+        ..Default::default()
+      };
+      let expected = decode_with(content.as_bytes(), &options).to_string()?;
+      assert_eq!(Decoded::new(content, options).to_string(), expected);
+    }
+  }
+  Ok(())
+}
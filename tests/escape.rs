@@ -1,10 +1,21 @@
-use std::borrow::Cow;
+#![allow(clippy::needless_return, clippy::manual_unwrap_or_default)]
+use std::{borrow::Cow, collections::HashMap};
 
 use htmlentity::{
+  charset::CharSet,
+  data::{LEGACY_ENTITIES, LETTER_ORDERED_ENTITIES},
   entity::{
-    decode, decode_chars, decode_chars_to, decode_to, encode, encode_char, encode_chars_with,
-    encode_with, CharacterSet, EncodeType, Entity, EntityType, ICodedDataTrait,
+    decode, decode_chars, decode_chars_to, decode_to, decode_with, decode_with_to, encode,
+    encode_char, encode_char_in, encode_char_with_format, encode_chars_with,
+    encode_chars_with_options, encode_for, encode_for_to, encode_for_with_format,
+    encode_for_with_format_to, encode_to, encode_with, encode_with_options, encode_with_options_to,
+    text_to_html, text_to_html_to, write_attribute, AttributeError, AttributeQuote, CharacterSet,
+    DecodeContext, DecodeErrorKind, DecodeMode, DecodeOptions, EncodeError, EncodeOptions,
+    EncodeType, Entity, EntityNamePreference, EntitySet, EntityType, EscapeContext,
+    ForbiddenCodePointPolicy, IBytesTrait, ICodedDataTrait, LineBreakMode, NumericFormat,
+    QuotePolicy, TextToHtmlOptions, XmlVersion,
   },
+  trie::ENTITY_TRIE,
   types::{AnyhowResult, ByteList},
 };
 
@@ -22,7 +33,44 @@ fn test_entity_decode() -> AnyhowResult<()> {
   assert!(Entity::decode_chars(&['l', 't', '好']).is_err());
   let ok_char = Entity::decode_chars(&['l', 't']);
   assert!(ok_char.is_ok());
-  assert_eq!(ok_char?, '<');
+  assert_eq!(*ok_char?, ['<']);
+  // multiple code points
+  assert_eq!(*Entity::decode(b"NotEqualTilde")?, ['\u{2242}', '\u{338}']);
+  assert_eq!(
+    *Entity::decode_chars(&['f', 'j', 'l', 'i', 'g'])?,
+    ['f', 'j']
+  );
+  // names only in the whatwg list
+  assert_eq!(*Entity::decode(b"bigstar")?, ['\u{2605}']);
+  assert_eq!(*Entity::decode(b"ohm")?, ['\u{3a9}']);
+  // the html4 name is still chosen when encoding
+  assert_eq!(
+    encode_char(&'\u{203e}', &EncodeType::Named).map(|entity| entity.to_string()),
+    Some(String::from("&oline;"))
+  );
+  Ok(())
+}
+
+#[test]
+fn test_decode_multiple_code_points() -> AnyhowResult<()> {
+  let content = "a&NotEqualTilde;b&fjlig;c";
+  let decoded_data = decode(content.as_bytes());
+  assert!(decoded_data.is_ok());
+  assert_eq!(decoded_data.entity_count(), 2);
+  assert_eq!(decoded_data.to_string()?, "a\u{2242}\u{338}bfjc");
+  assert_eq!(
+    decoded_data.to_chars()?,
+    vec!['a', '\u{2242}', '\u{338}', 'b', 'f', 'j', 'c']
+  );
+  assert_eq!(decoded_data.to_bytes(), "a\u{2242}\u{338}bfjc".as_bytes());
+  let mut data = vec![];
+  decode_to(content.as_bytes(), &mut data);
+  assert_eq!(data, "a\u{2242}\u{338}bfjc".as_bytes());
+  let chars = content.chars().collect::<Vec<char>>();
+  assert_eq!(
+    decode_chars(&chars).iter().collect::<String>(),
+    "a\u{2242}\u{338}bfjc"
+  );
   Ok(())
 }
 
@@ -114,7 +162,7 @@ fn test_escape() -> AnyhowResult<()> {
   let chars = String::from("<div class='header'></div>")
     .chars()
     .collect::<Vec<char>>();
-  let character_set = CharacterSet::HtmlAndNonASCII;
+  let character_set = CharacterSet::HtmlAndNonLatin1;
   let encoded_chars = encode_chars_with(&chars, |ch| {
     if character_set.contains(ch) || *ch == '\'' {
       return Some(&EncodeType::Named);
@@ -166,11 +214,11 @@ fn test_decode_hex() {
   // hex with leading zeros
   let content = "&#x0002192;";
   assert_eq!(decode_to_string(content), "→");
-  // wrong hex unicode ranges
+  // wrong hex unicode ranges are replaced
   let content = "&#x110000;";
-  assert_eq!(decode_to_string(content), content);
+  assert_eq!(decode_to_string(content), "\u{fffd}");
   let content = "&#xDC00;";
-  assert_eq!(decode_to_string(content), content);
+  assert_eq!(decode_to_string(content), "\u{fffd}");
   // wrong hex
   let content = "&#xa0fh;";
   assert_eq!(decode_to_string(content), content);
@@ -187,18 +235,50 @@ fn test_decode_decimal() {
   // decimal with leading zeros
   let content = "&#0008594;";
   assert_eq!(decode_to_string(content), "→");
-  // wrong decimal unicode ranges
+  // wrong decimal unicode ranges are replaced
   let content = "&#1114112;";
-  assert_eq!(decode_to_string(content), content);
+  assert_eq!(decode_to_string(content), "\u{fffd}");
   let content = "&#56320;";
-  assert_eq!(decode_to_string(content), content);
+  assert_eq!(decode_to_string(content), "\u{fffd}");
+}
+
+#[test]
+fn test_decode_numeric_spec() -> AnyhowResult<()> {
+  // overflow never wraps around
+  assert_eq!(decode_to_string("&#4294967356;"), "\u{fffd}");
+  assert_eq!(decode_to_string("&#x10000003c;"), "\u{fffd}");
+  // the references with more than 32 significant digits are kept in the strict mode
+  let content = format!("&#x{};", "f".repeat(33));
+  assert_eq!(decode_to_string(&content), content);
+  let content = format!("&#000{};", "9".repeat(32));
+  assert_eq!(decode_to_string(&content), "\u{fffd}");
+  assert_eq!(
+    decode_to_string("&#99999999999999999999999999999999;"),
+    "\u{fffd}"
+  );
+  // any length of digits
+  assert_eq!(decode_to_string("&#0000000000000000000060;"), "<");
+  assert_eq!(decode_to_string("&#x000000000000000000003c;"), "<");
+  // null character
+  assert_eq!(decode_to_string("&#0;&#x0;"), "\u{fffd}\u{fffd}");
+  // the c1 controls are remapped through windows-1252
+  assert_eq!(decode_to_string("&#128;&#x9f;"), "\u{20ac}\u{178}");
+  assert_eq!(decode_to_string("&#x81;"), "\u{81}");
+  // noncharacters and controls are kept, but reported
+  let decoded_data = decode(b"&#xfdd0;&#x1fffe;&#1;&#13;&#9;");
+  assert_eq!(decoded_data.to_string()?, "\u{fdd0}\u{1fffe}\u{1}\r\t");
+  assert_eq!(decoded_data.get_errors().len(), 4);
+  // entity decode
+  assert_eq!(*Entity::decode(b"#4294967356")?, ['\u{fffd}']);
+  assert_eq!(*Entity::decode(b"#x80")?, ['\u{20ac}']);
+  Ok(())
 }
 
 #[test]
 fn test_exclude_named() -> AnyhowResult<()> {
   let html = "<div class='header'>℗</div>";
   let encode_type = EncodeType::Named;
-  let entity_set = CharacterSet::SpecialCharsAndNonASCII;
+  let entity_set = CharacterSet::SpecialCharsAndNonLatin1;
   let html_encoded = encode_with(html.as_bytes(), &encode_type, |ch, _| {
     if *ch == '<' {
       return (false, None);
@@ -281,3 +361,1247 @@ fn test_unexpected() -> AnyhowResult<()> {
   assert_eq!(decode(b"&#1&lt;").to_string()?, "&#1<");
   Ok(())
 }
+
+#[test]
+fn test_encode_wrong_utf8() {
+  let encode_bytes = |content: &[u8]| -> ByteList {
+    let mut data = vec![];
+    encode_to(content, &EncodeType::Named, &CharacterSet::Html, &mut data);
+    data
+  };
+  // the incomplete character at the end is kept
+  assert_eq!(encode_bytes(b"<\xe4\xb8"), b"&lt;\xe4\xb8");
+  // the byte after the incomplete character is still encoded
+  assert_eq!(encode_bytes(b"\xe4\xb8<\xc3>"), b"\xe4\xb8&lt;\xc3&gt;");
+  assert_eq!(
+    encode_bytes(b"\x80<\xed\xa0\x80>"),
+    b"\x80&lt;\xed\xa0\x80&gt;"
+  );
+  assert!(
+    encode(b"a\xe4\xb8", &EncodeType::Named, &CharacterSet::Html)
+      .to_string()
+      .is_err()
+  );
+}
+
+#[test]
+fn test_decode_html5_legacy() -> AnyhowResult<()> {
+  let options = DecodeOptions {
+    mode: DecodeMode::Html5,
+    ..Default::default()
+  };
+  let html5_decode = |content: &str| -> AnyhowResult<String> {
+    let decoded_data = decode_with(content.as_bytes(), &options);
+    let mut data = vec![];
+    decode_with_to(content.as_bytes(), &options, &mut data);
+    assert_eq!(decoded_data.to_bytes(), data);
+    decoded_data.to_string()
+  };
+  assert_eq!(html5_decode("&copy 2024")?, "\u{a9} 2024");
+  assert_eq!(html5_decode("a&ampb")?, "a&b");
+  assert_eq!(html5_decode("&nbsp&eacute;&eacute")?, "\u{a0}\u{e9}\u{e9}");
+  // the longest legacy name
+  assert_eq!(
+    html5_decode("&notin;&notit;&notx")?,
+    "\u{2209}\u{ac}it;\u{ac}x"
+  );
+  // non legacy names must end with ';'
+  assert_eq!(html5_decode("&hellip &hellip;")?, "&hellip \u{2026}");
+  // numeric references without ';'
+  assert_eq!(html5_decode("&#60&#x3cx &#x")?, "<<x &#x");
+  assert_eq!(html5_decode("&&;&#;")?, "&&;&#;");
+  // missing semicolons are recorded
+  let decoded_data = decode_with(b"&copy;&copy &#60", &options);
+  assert_eq!(decoded_data.entity_count(), 3);
+  let error_ranges = decoded_data
+    .get_errors()
+    .iter()
+    .map(|(range, _)| range.clone())
+    .collect::<Vec<_>>();
+  assert_eq!(error_ranges, vec![6..=10, 12..=15]);
+  // the strict mode keeps them
+  assert_eq!(decode_to_string("&copy 2024 a&ampb"), "&copy 2024 a&ampb");
+  Ok(())
+}
+
+#[test]
+fn test_decode_attribute_context() -> AnyhowResult<()> {
+  let options = DecodeOptions {
+    mode: DecodeMode::Html5,
+    context: DecodeContext::Attribute,
+    ..Default::default()
+  };
+  let attr_decode = |content: &str| -> AnyhowResult<String> {
+    let mut data = vec![];
+    decode_with_to(content.as_bytes(), &options, &mut data);
+    let decoded_string = decode_with(content.as_bytes(), &options).to_string()?;
+    assert_eq!(decoded_string.as_bytes(), data);
+    Ok(decoded_string)
+  };
+  // followed by '=' or alphanumeric
+  assert_eq!(attr_decode("?a=1&copy=2")?, "?a=1&copy=2");
+  assert_eq!(attr_decode("?a=1&amp=2&ampx")?, "?a=1&amp=2&ampx");
+  assert_eq!(attr_decode("&notit;")?, "&notit;");
+  // followed by other characters
+  assert_eq!(attr_decode("&copy 2024&copy")?, "\u{a9} 2024\u{a9}");
+  assert_eq!(attr_decode("a&amp-b")?, "a&-b");
+  // the references end with ';' and the numeric references are always decoded
+  assert_eq!(attr_decode("?a=1&copy;=2&#60=")?, "?a=1\u{a9}=2<=");
+  // the text context decode them
+  let options = DecodeOptions {
+    mode: DecodeMode::Html5,
+    context: DecodeContext::Text,
+    ..Default::default()
+  };
+  assert_eq!(
+    decode_with(b"?a=1&copy=2", &options).to_string()?,
+    "?a=1\u{a9}=2"
+  );
+  Ok(())
+}
+
+#[test]
+fn test_decode_error_kinds() {
+  use DecodeErrorKind::*;
+  let error_kinds = |content: &[u8], options: &DecodeOptions| {
+    decode_with(content, options)
+      .get_errors()
+      .iter()
+      .map(|(range, kind)| (range.clone(), *kind))
+      .collect::<Vec<_>>()
+  };
+  let strict = DecodeOptions::default();
+  let html5 = DecodeOptions {
+    mode: DecodeMode::Html5,
+    ..Default::default()
+  };
+  assert_eq!(
+    error_kinds(b"&foo;&#;&#xg;", &strict),
+    vec![
+      (0..=4, UnknownNamedCharacterReference),
+      (5..=6, AbsenceOfDigitsInNumericCharacterReference),
+      (8..=10, AbsenceOfDigitsInNumericCharacterReference),
+    ]
+  );
+  assert_eq!(
+    error_kinds(b"AT&T &copy &#60", &strict),
+    vec![
+      (5..=9, MissingSemicolonAfterCharacterReference),
+      (11..=14, MissingSemicolonAfterCharacterReference),
+    ]
+  );
+  assert_eq!(
+    error_kinds(b"&#0;&#x110000;&#xd800;&#xfffe;&#x7f;", &strict),
+    vec![
+      (0..=3, NullCharacterReference),
+      (4..=13, CharacterReferenceOutsideUnicodeRange),
+      (14..=21, SurrogateCharacterReference),
+      (22..=29, NoncharacterCharacterReference),
+      (30..=35, ControlCharacterReference),
+    ]
+  );
+  assert_eq!(
+    error_kinds(b"&copy&#128", &html5),
+    vec![
+      (0..=4, MissingSemicolonAfterCharacterReference),
+      (5..=9, MissingSemicolonAfterCharacterReference),
+      (5..=9, ControlCharacterReference),
+    ]
+  );
+  // a literal '&' is not a parse error
+  assert_eq!(error_kinds(b"a&b a & b", &strict), vec![]);
+  assert_eq!(
+    UnknownNamedCharacterReference.code(),
+    "unknown-named-character-reference"
+  );
+  // entity decode
+  assert_eq!(Entity::decode(b""), Err(UnknownNamedCharacterReference));
+  assert_eq!(Entity::decode(b"a b"), Err(UnknownNamedCharacterReference));
+  assert_eq!(Entity::decode(b"foo"), Err(UnknownNamedCharacterReference));
+  assert_eq!(
+    Entity::decode(b"#xg"),
+    Err(AbsenceOfDigitsInNumericCharacterReference)
+  );
+  assert_eq!(
+    Entity::decode(b"#12a"),
+    Err(MissingSemicolonAfterCharacterReference)
+  );
+  assert_eq!(
+    Entity::decode_chars(&['l', 't', '\u{597d}']),
+    Err(UnknownNamedCharacterReference)
+  );
+  // the numeric references are decoded, the errors of the code points are only reported by `decode_with`
+  assert_eq!(Entity::decode(b"#0").as_deref(), Ok(&['\u{fffd}'][..]));
+  assert_eq!(Entity::decode(b"#xd800").as_deref(), Ok(&['\u{fffd}'][..]));
+  assert_eq!(
+    Entity::decode(b"#x110000").as_deref(),
+    Ok(&['\u{fffd}'][..])
+  );
+  assert_eq!(Entity::decode(b"#128").as_deref(), Ok(&['\u{20ac}'][..]));
+  assert_eq!(
+    error_kinds(b"&#0;&#xd800;&#x110000;&#128;", &strict),
+    vec![
+      (0..=3, NullCharacterReference),
+      (4..=11, SurrogateCharacterReference),
+      (12..=21, CharacterReferenceOutsideUnicodeRange),
+      (22..=27, ControlCharacterReference),
+    ]
+  );
+}
+
+#[test]
+fn test_entity_trie() {
+  for &(name, chars) in LETTER_ORDERED_ENTITIES.iter() {
+    assert_eq!(ENTITY_TRIE.get(name), Some(chars));
+    let mut bytes = name.to_vec();
+    bytes.push(b';');
+    let entity_match = ENTITY_TRIE.longest_match(&bytes);
+    assert!(entity_match.is_some());
+    let entity_match = entity_match.unwrap();
+    assert_eq!(entity_match.name, name);
+    assert!(entity_match.has_semicolon);
+    assert_eq!(entity_match.bytes_len(), bytes.len());
+  }
+  for &name in LEGACY_ENTITIES.iter() {
+    let mut bytes = name.to_vec();
+    bytes.extend_from_slice(b" x");
+    let entity_match = ENTITY_TRIE.longest_match(&bytes).map(|m| m.name);
+    assert_eq!(entity_match, Some(name));
+  }
+  // the longest prefix
+  assert_eq!(
+    ENTITY_TRIE.longest_match(b"ampx").map(|m| m.name),
+    Some(&b"amp"[..])
+  );
+  assert_eq!(
+    ENTITY_TRIE.longest_match(b"notinva;").map(|m| m.name),
+    Some(&b"notinva"[..])
+  );
+  assert_eq!(
+    ENTITY_TRIE.longest_match(b"notinvx;").map(|m| m.name),
+    Some(&b"not"[..])
+  );
+  assert_eq!(ENTITY_TRIE.get(b""), None);
+  assert_eq!(ENTITY_TRIE.get(b"no"), None);
+  assert_eq!(ENTITY_TRIE.longest_match(b"no;"), None);
+  assert_eq!(ENTITY_TRIE.longest_match(b""), None);
+}
+
+#[test]
+fn test_escape_context() -> AnyhowResult<()> {
+  let ascii: String = (0u8..0x80).map(|byte| byte as char).collect();
+  let payloads = [
+    ascii.as_str(),
+    "</title><script>alert(1)</script>",
+    "\" onmouseover=\"alert(1)",
+    "' onmouseover='alert(1)",
+    "a onmouseover=alert(1)",
+    "x\tautofocus\nonfocus=alert(1)`",
+    "--><script>alert(1)</script><!--",
+    "->--!><!-",
+    ">",
+    "&lt;&amp;&#60;&quot;",
+    "\u{4e16}\u{754c}\u{1f600}",
+  ];
+  let encode_types = [
+    EncodeType::Named,
+    EncodeType::Hex,
+    EncodeType::NamedOrDecimal,
+  ];
+  for payload in payloads {
+    for encode_type in &encode_types {
+      let encode_to_string = |context: EscapeContext| -> AnyhowResult<String> {
+        let encoded = encode_for(&context, payload.as_bytes(), encode_type).to_string()?;
+        let mut data = vec![];
+        encode_for_to(&context, payload.as_bytes(), encode_type, &mut data);
+        assert_eq!(encoded.as_bytes(), data);
+        Ok(encoded)
+      };
+      // element text can't start a tag
+      let text = encode_to_string(EscapeContext::Text)?;
+      assert!(!text.contains(['<', '>']));
+      assert_eq!(decode_to_string(&text), payload);
+      // the quoted attributes can't be closed
+      let double_quoted = encode_to_string(EscapeContext::DoubleQuotedAttribute)?;
+      assert!(!double_quoted.contains('"'));
+      assert_eq!(decode_to_string(&double_quoted), payload);
+      let single_quoted = encode_to_string(EscapeContext::SingleQuotedAttribute)?;
+      assert!(!single_quoted.contains('\''));
+      assert_eq!(decode_to_string(&single_quoted), payload);
+      // the unquoted attribute can't be ended
+      let unquoted = encode_to_string(EscapeContext::UnquotedAttribute)?;
+      assert!(!unquoted.contains(['\t', '\n', '\x0c', '\r', ' ', '"', '\'', '=', '<', '>', '`']));
+      assert_eq!(decode_to_string(&unquoted), payload);
+      // the rcdata can't be closed by an end tag
+      let rcdata = encode_to_string(EscapeContext::RcData)?;
+      assert!(!rcdata.contains('<'));
+      assert_eq!(decode_to_string(&rcdata), payload);
+      // the comment can't be closed or nested
+      let comment = encode_to_string(EscapeContext::Comment)?;
+      for sequence in ["-->", "--!>", "<!--"] {
+        assert!(!comment.contains(sequence));
+      }
+      assert!(!comment.starts_with('>') && !comment.starts_with("->") && !comment.ends_with("<!-"));
+      // the srcdoc is decoded twice
+      let srcdoc = encode_to_string(EscapeContext::Srcdoc)?;
+      assert!(!srcdoc.contains(['"', '<', '>']));
+      let inner_document = decode_to_string(&srcdoc);
+      assert!(!inner_document.contains(['<', '>']));
+      assert_eq!(decode_to_string(&inner_document), payload);
+      // the public filter escapes the srcdoc twice too
+      let filtered = encode_with(payload.as_bytes(), encode_type, |ch, encode_type| {
+        EscapeContext::Srcdoc.filter(ch, encode_type)
+      })
+      .to_string()?;
+      assert_eq!(filtered, srcdoc);
+    }
+  }
+  // the srcdoc entity of the text has the escaped '&'
+  let (_, entity) = EscapeContext::Srcdoc.filter(&'<', &EncodeType::Hex);
+  assert_eq!(
+    entity,
+    Some((EntityType::AmpEscaped, Cow::Owned(b"#x3c".to_vec())))
+  );
+  let format = NumericFormat {
+    uppercase_digits: true,
+    uppercase_prefix: true,
+    min_width: 4,
+  };
+  assert_eq!(
+    encode_for_with_format(&EscapeContext::Srcdoc, b"<\"", &EncodeType::Hex, &format)
+      .to_string()?,
+    "&amp;#X003C;&#X0022;"
+  );
+  let mut data = vec![];
+  encode_for_with_format_to(
+    &EscapeContext::UnquotedAttribute,
+    b"a b",
+    &EncodeType::Decimal,
+    &format,
+    &mut data,
+  );
+  assert_eq!(data, b"a&#0032;b");
+  Ok(())
+}
+
+#[test]
+fn test_write_attribute() {
+  let attribute = |name: &str, value: &str, policy: QuotePolicy| {
+    let mut data = vec![];
+    write_attribute(name.as_bytes(), value.as_bytes(), &policy, &mut data)
+      .map(|quote| (String::from_utf8(data).unwrap(), quote))
+  };
+  // auto quotes
+  assert_eq!(
+    attribute("title", "a \"b\" 'c'", QuotePolicy::Auto),
+    Ok((
+      String::from("title=\"a &quot;b&quot; 'c'\""),
+      AttributeQuote::Double
+    ))
+  );
+  assert_eq!(
+    attribute("title", "a \"b\"", QuotePolicy::Auto),
+    Ok((String::from("title='a \"b\"'"), AttributeQuote::Single))
+  );
+  assert_eq!(
+    attribute("title", "it's", QuotePolicy::Auto),
+    Ok((String::from("title=\"it's\""), AttributeQuote::Double))
+  );
+  // fixed quotes
+  assert_eq!(
+    attribute("title", "a\"'&<", QuotePolicy::Double),
+    Ok((
+      String::from("title=\"a&quot;'&amp;<\""),
+      AttributeQuote::Double
+    ))
+  );
+  assert_eq!(
+    attribute("title", "a\"'&<", QuotePolicy::Single),
+    Ok((
+      String::from("title='a\"&apos;&amp;<'"),
+      AttributeQuote::Single
+    ))
+  );
+  // unquoted
+  assert_eq!(
+    attribute("class", "a&b", QuotePolicy::Minimal),
+    Ok((String::from("class=a&amp;b"), AttributeQuote::None))
+  );
+  assert_eq!(
+    attribute("class", "a&b", QuotePolicy::Unquoted),
+    Ok((String::from("class=a&amp;b"), AttributeQuote::None))
+  );
+  assert_eq!(
+    attribute("class", "", QuotePolicy::Minimal),
+    Ok((String::from("class=\"\""), AttributeQuote::Double))
+  );
+  for value in ["", "a b", "a=b", "a>", "`a", "a'", "\"a", "a\tb"] {
+    assert_eq!(
+      attribute("class", value, QuotePolicy::Unquoted),
+      Err(AttributeError::UnsafeUnquotedValue)
+    );
+  }
+  // invalid names
+  for name in [
+    "",
+    "a b",
+    "a=",
+    "a/",
+    "a>",
+    "\"a",
+    "'a",
+    "a\u{0}",
+    "a\u{7f}",
+    "a\u{fffe}",
+  ] {
+    assert_eq!(
+      attribute(name, "value", QuotePolicy::Auto),
+      Err(AttributeError::InvalidName)
+    );
+  }
+  assert!(attribute("data-\u{4e16}", "value", QuotePolicy::Auto).is_ok());
+  assert!(attribute(":on-click.prevent", "value", QuotePolicy::Auto).is_ok());
+}
+
+#[test]
+fn test_xml_mode() -> AnyhowResult<()> {
+  let error_kinds = |content: &[u8], options: &DecodeOptions| {
+    decode_with(content, options)
+      .get_errors()
+      .iter()
+      .map(|(_, kind)| *kind)
+      .collect::<Vec<_>>()
+  };
+  let xml10 = DecodeOptions {
+    mode: DecodeMode::Xml(XmlVersion::V1_0),
+    ..Default::default()
+  };
+  let xml11 = DecodeOptions {
+    mode: DecodeMode::Xml(XmlVersion::V1_1),
+    ..Default::default()
+  };
+  // only the predefined entities are decoded
+  let content = b"&lt;&gt;&amp;&quot;&apos;&copy;&nbsp;&lt";
+  assert_eq!(
+    decode_with(content, &xml10).to_string()?,
+    "<>&\"'&copy;&nbsp;&lt"
+  );
+  assert_eq!(
+    error_kinds(content, &xml10),
+    vec![
+      DecodeErrorKind::UnknownNamedCharacterReference,
+      DecodeErrorKind::UnknownNamedCharacterReference,
+      DecodeErrorKind::MissingSemicolonAfterCharacterReference,
+    ]
+  );
+  // the bare '&' is not well-formed
+  let decoded_data = decode_with(b"a & b&", &xml10);
+  assert_eq!(decoded_data.to_string()?, "a & b&");
+  assert_eq!(
+    decoded_data.get_errors(),
+    [
+      (2..=2, DecodeErrorKind::AmbiguousAmpersand),
+      (5..=5, DecodeErrorKind::AmbiguousAmpersand),
+    ]
+  );
+  assert!(decode_with(b"a & b&", &DecodeOptions::default())
+    .get_errors()
+    .is_empty());
+  // the numeric references are not remapped, and must be a legal xml character
+  let content = b"&#128;&#x3c;&#X3c;&#1;&#0;&#xd800;&#xfffe;&#x110000;&#9;";
+  assert_eq!(
+    decode_with(content, &xml10).to_string()?,
+    "\u{80}<&#X3c;&#1;&#0;&#xd800;&#xfffe;&#x110000;\t"
+  );
+  assert_eq!(
+    decode_with(content, &xml11).to_string()?,
+    "\u{80}<&#X3c;\u{1}&#0;&#xd800;&#xfffe;&#x110000;\t"
+  );
+  assert_eq!(
+    error_kinds(content, &xml10),
+    vec![
+      DecodeErrorKind::AbsenceOfDigitsInNumericCharacterReference,
+      DecodeErrorKind::InvalidXmlCharacterReference,
+      DecodeErrorKind::InvalidXmlCharacterReference,
+      DecodeErrorKind::InvalidXmlCharacterReference,
+      DecodeErrorKind::InvalidXmlCharacterReference,
+      DecodeErrorKind::InvalidXmlCharacterReference,
+    ]
+  );
+  // encode
+  let encode_xml = |content: &str, encode_type: EncodeType, xml_version: XmlVersion| {
+    let options = EncodeOptions {
+      encode_type,
+      charset: CharacterSet::SpecialCharsAndNonLatin1,
+      xml_version: Some(xml_version),
+      ..Default::default()
+    };
+    encode_with_options(content.as_bytes(), &options)?.to_string()
+  };
+  let content = "<\u{a9}\u{4e16}\r\u{1}\u{85}\u{fffe}'>";
+  assert_eq!(
+    encode_xml(content, EncodeType::Named, XmlVersion::V1_0)?,
+    "&lt;\u{a9}\u{4e16}&#xd;&#xfffd;\u{85}&#xfffd;&apos;&gt;"
+  );
+  assert_eq!(
+    encode_xml(content, EncodeType::NamedOrDecimal, XmlVersion::V1_1)?,
+    "&lt;\u{a9}&#19990;&#13;&#1;&#133;&#65533;&apos;&gt;"
+  );
+  assert_eq!(
+    encode_xml(content, EncodeType::Hex, XmlVersion::V1_1)?,
+    "&#x3c;\u{a9}&#x4e16;&#xd;&#x1;&#x85;&#xfffd;&#x27;&#x3e;"
+  );
+  // the encoded content can be decoded back
+  let encoded = encode_xml(
+    "<\u{4e16}\r\u{1}\u{85}>",
+    EncodeType::Named,
+    XmlVersion::V1_1,
+  )?;
+  assert_eq!(
+    decode_with(encoded.as_bytes(), &xml11).to_string()?,
+    "<\u{4e16}\r\u{1}\u{85}>"
+  );
+  Ok(())
+}
+
+#[test]
+fn test_entity_name_preference() -> AnyhowResult<()> {
+  let encode_prefer = |content: &str, name_preference: EntityNamePreference| {
+    let options = EncodeOptions {
+      encode_type: EncodeType::NamedOrHex,
+      charset: CharacterSet::All,
+      name_preference,
+      ..Default::default()
+    };
+    encode_with_options(content.as_bytes(), &options)?.to_string()
+  };
+  let content = "\t\u{a8}\u{a0}\u{2016}\u{3d1}\u{2061}";
+  assert_eq!(
+    encode_prefer(content, EntityNamePreference::First)?,
+    "&Tab;&Dot;&nbsp;&Verbar;&thetav;&ApplyFunction;"
+  );
+  assert_eq!(
+    encode_prefer(content, EntityNamePreference::Shortest)?,
+    "&Tab;&die;&nbsp;&Vert;&thetav;&af;"
+  );
+  assert_eq!(
+    encode_prefer(content, EntityNamePreference::Html4)?,
+    "&Tab;&uml;&nbsp;&Verbar;&thetasym;&ApplyFunction;"
+  );
+  assert_eq!(
+    encode_prefer(content, EntityNamePreference::Lowercase)?,
+    "&Tab;&die;&nbsp;&Verbar;&thetav;&af;"
+  );
+  let names: HashMap<char, &'static [u8]> = HashMap::from([
+    ('\u{a0}', &b"NonBreakingSpace"[..]),
+    // not the name of the character
+    ('\u{2016}', &b"nbsp"[..]),
+  ]);
+  assert_eq!(
+    encode_prefer(
+      content,
+      EntityNamePreference::Custom {
+        names,
+        fallback: Box::new(EntityNamePreference::Html4)
+      }
+    )?,
+    "&Tab;&uml;&NonBreakingSpace;&Verbar;&thetasym;&ApplyFunction;"
+  );
+  // the first name is the same as the `encode_char` method
+  for ch in ['\t', '\u{a8}', '\u{2016}', '<'] {
+    assert_eq!(
+      EntityNamePreference::First
+        .find_name(ch)
+        .map(|name| name.to_vec()),
+      encode_char(&ch, &EncodeType::Named).map(|entity| entity.data())
+    );
+  }
+  Ok(())
+}
+
+#[test]
+fn test_entity_set() -> AnyhowResult<()> {
+  let content = "<\u{a9}\u{2026}'\u{2242}";
+  let encode_in = |encode_type: EncodeType, entity_set: EntitySet| {
+    let options = EncodeOptions {
+      encode_type,
+      charset: CharacterSet::All,
+      entity_set,
+      ..Default::default()
+    };
+    encode_with_options(content.as_bytes(), &options)?.to_string()
+  };
+  assert_eq!(
+    encode_in(EncodeType::NamedOrHex, EntitySet::Html5)?,
+    "&lt;&copy;&hellip;&apos;&esim;"
+  );
+  assert_eq!(
+    encode_in(EncodeType::NamedOrHex, EntitySet::MathMl)?,
+    "&lt;&copy;&hellip;&apos;&esim;"
+  );
+  assert_eq!(
+    encode_in(EncodeType::NamedOrHex, EntitySet::Xhtml1)?,
+    "&lt;&copy;&hellip;&apos;&#x2242;"
+  );
+  assert_eq!(
+    encode_in(EncodeType::NamedOrHex, EntitySet::Html4)?,
+    "&lt;&copy;&hellip;&#x27;&#x2242;"
+  );
+  assert_eq!(
+    encode_in(EncodeType::NamedOrDecimal, EntitySet::Html32)?,
+    "&lt;&copy;&#8230;&#39;&#8770;"
+  );
+  // the names out of the set fall back to the hex entities
+  assert_eq!(
+    encode_in(EncodeType::Named, EntitySet::Html32)?,
+    "&lt;&copy;&#x2026;&#x27;&#x2242;"
+  );
+  let options = EncodeOptions {
+    encode_type: EncodeType::Named,
+    charset: CharacterSet::SpecialChars,
+    entity_set: EntitySet::Html4,
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_with_options(b"it's", &options)?.to_string()?,
+    "it&#x27;s"
+  );
+  assert_eq!(
+    encode_char_in(&'\'', &EncodeType::NamedOrDecimal, &EntitySet::Html4)
+      .map(|entity| entity.to_string()),
+    Some(String::from("&#39;"))
+  );
+  // decode
+  let decode_in = |content: &[u8], mode: DecodeMode, entity_set: EntitySet| {
+    let options = DecodeOptions {
+      mode,
+      entity_set,
+      ..Default::default()
+    };
+    let decoded_data = decode_with(content, &options);
+    let errors = decoded_data
+      .get_errors()
+      .iter()
+      .map(|(_, kind)| *kind)
+      .collect::<Vec<_>>();
+    (decoded_data.to_string().unwrap(), errors)
+  };
+  let content = b"&lt;&copy;&hellip;&apos;&eqsim;";
+  assert_eq!(
+    decode_in(content, DecodeMode::Strict, EntitySet::Html5),
+    (String::from("<\u{a9}\u{2026}'\u{2242}"), vec![])
+  );
+  assert_eq!(
+    decode_in(content, DecodeMode::Strict, EntitySet::Html4),
+    (
+      String::from("<\u{a9}\u{2026}&apos;&eqsim;"),
+      vec![
+        DecodeErrorKind::UnknownNamedCharacterReference,
+        DecodeErrorKind::UnknownNamedCharacterReference
+      ]
+    )
+  );
+  assert_eq!(
+    decode_in(content, DecodeMode::Strict, EntitySet::Html32).0,
+    "<\u{a9}&hellip;&apos;&eqsim;"
+  );
+  assert_eq!(
+    decode_in(content, DecodeMode::Strict, EntitySet::Xhtml1).0,
+    "<\u{a9}\u{2026}'&eqsim;"
+  );
+  // the legacy entities without ';'
+  let content = b"&copy &notin; &LT";
+  assert_eq!(
+    decode_in(content, DecodeMode::Html5, EntitySet::Html5).0,
+    "\u{a9} \u{2209} <"
+  );
+  assert_eq!(
+    decode_in(content, DecodeMode::Html5, EntitySet::Html32).0,
+    "\u{a9} \u{ac}in; &LT"
+  );
+  assert_eq!(
+    decode_in(content, DecodeMode::Html5, EntitySet::MathMl).0,
+    "&copy \u{2209} &LT"
+  );
+  Ok(())
+}
+
+#[test]
+fn test_numeric_format() -> AnyhowResult<()> {
+  let encode_char_to_string = |ch: char, encode_type: EncodeType, format: NumericFormat| {
+    encode_char_with_format(&ch, &encode_type, &format)
+      .map(|entity| entity.to_string())
+      .unwrap_or_default()
+  };
+  let upper = NumericFormat {
+    uppercase_digits: true,
+    ..Default::default()
+  };
+  let upper_prefix = NumericFormat {
+    uppercase_digits: true,
+    uppercase_prefix: true,
+    ..Default::default()
+  };
+  let padded = NumericFormat {
+    min_width: 4,
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_char_to_string('<', EncodeType::Hex, NumericFormat::default()),
+    "&#x3c;"
+  );
+  assert_eq!(encode_char_to_string('<', EncodeType::Hex, upper), "&#x3C;");
+  assert_eq!(
+    encode_char_to_string('<', EncodeType::Hex, upper_prefix),
+    "&#X3C;"
+  );
+  assert_eq!(
+    encode_char_to_string('<', EncodeType::Hex, padded),
+    "&#x003c;"
+  );
+  assert_eq!(
+    encode_char_to_string('<', EncodeType::Decimal, padded),
+    "&#0060;"
+  );
+  assert_eq!(
+    encode_char_to_string('<', EncodeType::NamedOrHex, padded),
+    "&lt;"
+  );
+  // the digits are not truncated by the width
+  assert_eq!(
+    encode_char_to_string('\u{1f600}', EncodeType::Hex, padded),
+    "&#x1f600;"
+  );
+  // the shortest entity
+  let shortest =
+    |ch: char| encode_char_to_string(ch, EncodeType::Shortest, NumericFormat::default());
+  assert_eq!(shortest('<'), "&lt;");
+  assert_eq!(shortest('\''), "&#39;");
+  assert_eq!(shortest('a'), "&#97;");
+  assert_eq!(shortest('\u{a0}'), "&nbsp;");
+  assert_eq!(shortest('\u{2242}'), "&esim;");
+  assert_eq!(shortest('\u{4e16}'), "&#19990;");
+  assert_eq!(shortest('\u{ffffd}'), "&#xffffd;");
+  assert_eq!(shortest('\u{fffd}'), "&#65533;");
+  assert_eq!(
+    encode_char_to_string('\u{a0}', EncodeType::Shortest, padded),
+    "&nbsp;"
+  );
+  assert_eq!(
+    encode_char_to_string('\u{ffffd}', EncodeType::Shortest, upper_prefix),
+    "&#XFFFFD;"
+  );
+  let entity = encode_char_with_format(&'\u{ffffd}', &EncodeType::Shortest, &upper_prefix).unwrap();
+  assert_eq!(entity.entity_type(), &EntityType::UpperHex);
+  assert_eq!(entity.bytes_len(), 9);
+  assert_eq!(
+    (0..entity.bytes_len())
+      .filter_map(|index| entity.byte(index).copied())
+      .collect::<Vec<u8>>(),
+    entity.to_bytes()
+  );
+  // the encode methods
+  let content = "<a title='\u{a0}'>\u{4e16}</a>";
+  assert_eq!(
+    encode(
+      content.as_bytes(),
+      &EncodeType::Shortest,
+      &CharacterSet::SpecialCharsAndNonLatin1
+    )
+    .to_string()?,
+    "&lt;a title=&#39;\u{a0}&#39;&gt;&#19990;&lt;/a&gt;"
+  );
+  let options = EncodeOptions {
+    encode_type: EncodeType::NamedOrHex,
+    charset: CharacterSet::SpecialCharsAndNonLatin1,
+    numeric_format: upper_prefix,
+    ..Default::default()
+  };
+  let encoded_data = encode_with_options(content.as_bytes(), &options)?;
+  let expected = "&lt;a title=&apos;\u{a0}&apos;&gt;&#X4E16;&lt;/a&gt;";
+  assert_eq!(encoded_data.to_string()?, expected);
+  assert_eq!(encoded_data.to_bytes(), expected.as_bytes());
+  assert_eq!(decode(expected.as_bytes()).to_string()?, content);
+  let options = EncodeOptions {
+    encode_type: EncodeType::Decimal,
+    charset: CharacterSet::Html,
+    xml_version: Some(XmlVersion::V1_0),
+    numeric_format: padded,
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_with_options("<\r>".as_bytes(), &options)?.to_string()?,
+    "&#0060;&#0013;&#0062;"
+  );
+  Ok(())
+}
+
+#[test]
+fn test_non_ascii_charset() -> AnyhowResult<()> {
+  let content = "<\u{7f}\u{80}\u{a0}\u{e9}\u{ff}\u{100}\u{4e16}>";
+  let encode_to_string = |charset: CharacterSet| {
+    encode(content.as_bytes(), &EncodeType::NamedOrHex, &charset).to_string()
+  };
+  assert_eq!(
+    encode_to_string(CharacterSet::NonAscii)?,
+    "<\u{7f}&#x80;&nbsp;&eacute;&yuml;&Amacr;&#x4e16;>"
+  );
+  assert_eq!(
+    encode_to_string(CharacterSet::HtmlAndNonAscii)?,
+    "&lt;\u{7f}&#x80;&nbsp;&eacute;&yuml;&Amacr;&#x4e16;&gt;"
+  );
+  assert_eq!(
+    encode_to_string(CharacterSet::NonLatin1)?,
+    "<\u{7f}\u{80}\u{a0}\u{e9}\u{ff}&Amacr;&#x4e16;>"
+  );
+  assert_eq!(
+    encode_to_string(CharacterSet::SpecialCharsAndNonLatin1)?,
+    "&lt;\u{7f}\u{80}\u{a0}\u{e9}\u{ff}&Amacr;&#x4e16;&gt;"
+  );
+  assert!(CharacterSet::SpecialCharsAndNonAscii.contains(&'\''));
+  assert!(CharacterSet::SpecialCharsAndNonAscii.contains(&'\u{e9}'));
+  assert!(!CharacterSet::SpecialCharsAndNonLatin1.contains(&'\u{e9}'));
+  // the deprecated character sets keep the behaviour of the non latin-1 character sets
+  #[allow(deprecated)]
+  let deprecated = vec![
+    (CharacterSet::NonASCII, CharacterSet::NonLatin1),
+    (
+      CharacterSet::HtmlAndNonASCII,
+      CharacterSet::HtmlAndNonLatin1,
+    ),
+    (
+      CharacterSet::SpecialCharsAndNonASCII,
+      CharacterSet::SpecialCharsAndNonLatin1,
+    ),
+  ];
+  for (charset, replacement) in deprecated {
+    assert_eq!(encode_to_string(charset)?, encode_to_string(replacement)?);
+  }
+  Ok(())
+}
+
+#[test]
+fn test_invisible_charset() -> AnyhowResult<()> {
+  let content = "<a>\u{202e}txt.exe\u{202c} \u{200b}\u{feff}\u{2067}x\u{2069}</a>";
+  let encode_to_string = |encode_type: EncodeType, charset: CharacterSet| {
+    encode(content.as_bytes(), &encode_type, &charset).to_string()
+  };
+  // the named entities such as `&ZeroWidthSpace;` are not used
+  assert_eq!(
+    encode_to_string(EncodeType::Named, CharacterSet::HtmlAndInvisible)?,
+    "&lt;a&gt;&#x202e;txt.exe&#x202c; &#x200b;&#xfeff;&#x2067;x&#x2069;&lt;/a&gt;"
+  );
+  assert_eq!(
+    encode_to_string(EncodeType::NamedOrDecimal, CharacterSet::BidiControls)?,
+    "<a>&#8238;txt.exe&#8236; \u{200b}\u{feff}&#8295;x&#8297;</a>"
+  );
+  assert_eq!(
+    encode_to_string(EncodeType::Hex, CharacterSet::ZeroWidth)?,
+    "<a>\u{202e}txt.exe\u{202c} &#x200b;&#xfeff;\u{2067}x\u{2069}</a>"
+  );
+  assert!(CharacterSet::SpecialCharsAndInvisible.contains(&'"'));
+  assert!(CharacterSet::Invisible.contains(&'\u{200e}') && !CharacterSet::Invisible.contains(&'a'));
+  // the options keep the numeric entities
+  let options = EncodeOptions {
+    encode_type: EncodeType::NamedOrHex,
+    charset: CharacterSet::SpecialCharsAndInvisible,
+    numeric_format: NumericFormat {
+      uppercase_digits: true,
+      ..Default::default()
+    },
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_with_options("'\u{200d}".as_bytes(), &options)?.to_string()?,
+    "&apos;&#x200D;"
+  );
+  // the sets agree with the presets
+  let presets = vec![
+    CharacterSet::BidiControls,
+    CharacterSet::ZeroWidth,
+    CharacterSet::Invisible,
+    CharacterSet::HtmlAndInvisible,
+    CharacterSet::SpecialCharsAndInvisible,
+  ];
+  for preset in presets {
+    let set = CharSet::from(&preset);
+    for code in (0..0x700).chain(0x2000..0x2100).chain(0xfe00..0x10000) {
+      if let Some(ch) = char::from_u32(code) {
+        assert_eq!(set.contains(&ch), preset.contains(&ch));
+      }
+    }
+  }
+  Ok(())
+}
+
+#[test]
+fn test_forbidden_code_point_policy() -> AnyhowResult<()> {
+  let content =
+    "a\u{0}\u{8}\t\n\u{c}\r\n\r\u{7f}\u{9f}\u{a0}\u{fdd0}\u{fdef}\u{fdf0}\u{1fffe}\u{10ffff}\u{e9}";
+  let encode_by = |policy: ForbiddenCodePointPolicy| {
+    let options = EncodeOptions {
+      encode_type: EncodeType::NamedOrHex,
+      forbidden_code_points: policy,
+      ..Default::default()
+    };
+    encode_with_options(content.as_bytes(), &options)
+  };
+  assert_eq!(
+    encode_by(ForbiddenCodePointPolicy::Keep)?.to_string()?,
+    content
+  );
+  let encoded = encode_by(ForbiddenCodePointPolicy::Encode)?.to_string()?;
+  assert_eq!(
+    encoded,
+    "a&#x0;&#x8;\t\n\u{c}\r\n&#xd;&#x7f;&#x9f;\u{a0}&#xfdd0;&#xfdef;\u{fdf0}&#x1fffe;&#x10ffff;\u{e9}"
+  );
+  // U+0000 and the C1 controls with a windows-1252 mapping are not decoded back
+  assert_eq!(
+    decode_to_string(&encoded),
+    content
+      .replace('\u{0}', "\u{fffd}")
+      .replace('\u{9f}', "\u{178}")
+  );
+  let options = EncodeOptions {
+    encode_type: EncodeType::Decimal,
+    forbidden_code_points: ForbiddenCodePointPolicy::Encode,
+    ..Default::default()
+  };
+  let encoded = encode_with_options("\u{81}\u{8d}\u{8f}\u{90}\u{9d}".as_bytes(), &options)?;
+  assert_eq!(encoded.to_string()?, "&#129;&#141;&#143;&#144;&#157;");
+  assert_eq!(
+    decode_to_string(&encoded.to_string()?),
+    "\u{81}\u{8d}\u{8f}\u{90}\u{9d}"
+  );
+  assert_eq!(
+    encode_by(ForbiddenCodePointPolicy::Replace)?.to_string()?,
+    "a\u{fffd}\u{fffd}\t\n\u{c}\r\n\u{fffd}\u{fffd}\u{fffd}\u{a0}\u{fffd}\u{fffd}\u{fdf0}\u{fffd}\u{fffd}\u{e9}"
+  );
+  assert_eq!(
+    encode_by(ForbiddenCodePointPolicy::Strip)?.to_string()?,
+    "a\t\n\u{c}\r\n\u{a0}\u{fdf0}\u{e9}"
+  );
+  assert_eq!(
+    encode_by(ForbiddenCodePointPolicy::Error).err(),
+    Some(EncodeError::ForbiddenCodePoint(1, '\u{0}'))
+  );
+  // the replacement character is encoded by the character set
+  let options = EncodeOptions {
+    encode_type: EncodeType::Decimal,
+    charset: CharacterSet::HtmlAndNonAscii,
+    forbidden_code_points: ForbiddenCodePointPolicy::Replace,
+    ..Default::default()
+  };
+  let mut data = vec![];
+  encode_with_options_to("<\u{1}>".as_bytes(), &options, &mut data)?;
+  assert_eq!(data, b"&#60;&#65533;&#62;");
+  // nothing is written on errors, the index is the byte index
+  let options = EncodeOptions {
+    forbidden_code_points: ForbiddenCodePointPolicy::Error,
+    ..Default::default()
+  };
+  let mut data = vec![];
+  assert_eq!(
+    encode_with_options_to("\u{e9}\r".as_bytes(), &options, &mut data),
+    Err(EncodeError::ForbiddenCodePoint(2, '\r'))
+  );
+  assert!(data.is_empty());
+  // the wrong utf-8 bytes are kept
+  let options = EncodeOptions {
+    forbidden_code_points: ForbiddenCodePointPolicy::Strip,
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_with_options(b"\xff\x01", &options)?.to_bytes(),
+    b"\xff"
+  );
+  // the characters, the index is the index in the characters
+  let chars = content.chars().collect::<Vec<char>>();
+  let options = EncodeOptions {
+    forbidden_code_points: ForbiddenCodePointPolicy::Strip,
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_chars_with_options(&chars, &options)?
+      .iter()
+      .collect::<String>(),
+    "a\t\n\u{c}\r\n\u{a0}\u{fdf0}\u{e9}"
+  );
+  let chars = "\u{e9}\u{e9}\r".chars().collect::<Vec<char>>();
+  let options = EncodeOptions {
+    forbidden_code_points: ForbiddenCodePointPolicy::Error,
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_chars_with_options(&chars, &options),
+    Err(EncodeError::ForbiddenCodePoint(2, '\r'))
+  );
+  assert!(matches!(
+    encode_chars_with_options(&['a', 'b'], &options)?,
+    Cow::Borrowed(_)
+  ));
+  // the filter doesn't know the next character, so the CR is encoded
+  let options = EncodeOptions {
+    forbidden_code_points: ForbiddenCodePointPolicy::Encode,
+    ..Default::default()
+  };
+  assert!(options.filter(&'\r').0);
+  Ok(())
+}
+
+#[test]
+fn test_text_to_html() {
+  let to_html = |text: &str, options: &TextToHtmlOptions| {
+    String::from_utf8(text_to_html(text.as_bytes(), options)).unwrap()
+  };
+  let options = TextToHtmlOptions::default();
+  // the line breaks
+  assert_eq!(
+    to_html("a\r\nb\rc\n\rd", &options),
+    "a<br>\nb<br>\nc<br>\n<br>\nd"
+  );
+  assert_eq!(to_html("", &options), "");
+  assert_eq!(to_html("\n", &options), "<br>\n");
+  // the spaces and tabs
+  assert_eq!(to_html("a b", &options), "a b");
+  assert_eq!(to_html("a  b", &options), "a &nbsp;b");
+  assert_eq!(to_html("a   b", &options), "a &nbsp; b");
+  assert_eq!(to_html(" a", &options), "&nbsp;a");
+  assert_eq!(to_html("  a\n b", &options), "&nbsp; a<br>\n&nbsp;b");
+  assert_eq!(to_html("\ta", &options), "&nbsp; &nbsp; a");
+  // the spaces before the line breaks end with a no-break space
+  assert_eq!(to_html("a   \nb", &options), "a&nbsp; &nbsp;<br>\nb");
+  assert_eq!(to_html("a  ", &options), "a &nbsp;");
+  assert_eq!(to_html("a\n  \nb", &options), "a<br>\n&nbsp;&nbsp;<br>\nb");
+  assert_eq!(to_html("   ", &options), "&nbsp; &nbsp;");
+  let tab_options = TextToHtmlOptions {
+    encode_type: EncodeType::Hex,
+    tab_width: 2,
+    ..Default::default()
+  };
+  assert_eq!(to_html("a\t\tb", &tab_options), "a &#xa0; &#xa0;b");
+  // the character set
+  let options = TextToHtmlOptions {
+    encode_type: EncodeType::NamedOrHex,
+    charset: CharacterSet::HtmlAndNonAscii,
+    ..Default::default()
+  };
+  assert_eq!(
+    to_html("<\u{e9}> \u{4e16}", &options),
+    "&lt;&eacute;&gt; &#x4e16;"
+  );
+  // the paragraphs
+  let options = TextToHtmlOptions {
+    line_breaks: LineBreakMode::Paragraph,
+    ..Default::default()
+  };
+  assert_eq!(
+    to_html("\r\n\r\nfirst\r\nline\r\n\t\r\n\r\nsecond\r\n", &options),
+    "<p>first<br>\nline</p>\n<p>second</p>"
+  );
+  assert_eq!(to_html(" \n\t", &options), "");
+  // built on the `encode_to` method
+  let mut data = b"<div>".to_vec();
+  text_to_html_to(b"x&y", &options, &mut data);
+  let mut expected = b"<div><p>".to_vec();
+  encode_to(
+    b"x&y",
+    &EncodeType::Named,
+    &CharacterSet::Html,
+    &mut expected,
+  );
+  expected.extend_from_slice(b"</p>");
+  assert_eq!(data, expected);
+}
+
+#[test]
+fn test_markdown_escape() -> AnyhowResult<()> {
+  let markdown = |content: &str, encode_type: EncodeType| -> AnyhowResult<String> {
+    let encoded =
+      encode_for(&EscapeContext::Markdown, content.as_bytes(), &encode_type).to_string()?;
+    let mut data = vec![];
+    encode_for_to(
+      &EscapeContext::Markdown,
+      content.as_bytes(),
+      &encode_type,
+      &mut data,
+    );
+    assert_eq!(encoded.as_bytes(), data);
+    assert_eq!(decode_to_string(&encoded), content);
+    Ok(encoded)
+  };
+  assert_eq!(
+    markdown("**bold** _it_ `code` [link](url) a|b ~~del~~ \\*", EncodeType::Decimal)?,
+    "&#42;&#42;bold&#42;&#42; &#95;it&#95; &#96;code&#96; &#91;link&#93;(url) a&#124;b &#126;&#126;del&#126;&#126; &#92;&#42;"
+  );
+  // the html characters use the encode type
+  assert_eq!(
+    markdown("<a href=\"x\">&", EncodeType::Named)?,
+    "&lt;a href=\"x\"&gt;&amp;"
+  );
+  // the block markers at the start of a line
+  assert_eq!(
+    markdown(
+      "# title\n> quote\n- item\n+ item\n1. one\n12) two\n===\n    code\n\tcode",
+      EncodeType::Decimal
+    )?,
+    "&#35; title\n&#62; quote\n&#45; item\n&#43; item\n1&#46; one\n12&#41; two\n&#61;==\n&#32;   code\n&#9;code"
+  );
+  // the characters in the middle of a line are kept
+  assert_eq!(
+    markdown("a # b - c + d = e 1. f 2) 3.5", EncodeType::Hex)?,
+    "a # b - c + d = e 1. f 2) 3.5"
+  );
+  assert_eq!(
+    markdown("a\r\n#b\r-c", EncodeType::Named)?,
+    "a\r\n&#x23;b\r&#x2d;c"
+  );
+  // the character set has no line position awareness
+  let charset = CharacterSet::Markdown;
+  assert!(charset.contains(&'*') && charset.contains(&'<') && !charset.contains(&'#'));
+  assert_eq!(
+    encode(b"# *a*", &EncodeType::NamedOrHex, &charset).to_string()?,
+    "# &#x2a;a&#x2a;"
+  );
+  // the numeric format is used by the block markers and the markdown characters
+  let format = NumericFormat {
+    uppercase_digits: true,
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_for_with_format(&EscapeContext::Markdown, b"# *<", &EncodeType::Hex, &format)
+      .to_string()?,
+    "&#x23; &#x2A;&#x3C;"
+  );
+  assert_eq!(
+    charset
+      .filter_with_format(&'*', &EncodeType::Hex, &format)
+      .1,
+    Some((EntityType::Hex, Cow::Owned(b"2A".to_vec())))
+  );
+  assert_eq!(
+    charset
+      .filter_with_format(&'<', &EncodeType::NamedOrHex, &format)
+      .1,
+    Some((EntityType::Named, Cow::Borrowed(&b"lt"[..])))
+  );
+  assert_eq!(
+    charset.filter_with_format(&'a', &EncodeType::Hex, &format),
+    (false, None)
+  );
+  let set = CharSet::from(&charset);
+  for code in 0..0x100 {
+    let ch = char::from_u32(code).unwrap();
+    assert_eq!(set.contains(&ch), charset.contains(&ch));
+  }
+  Ok(())
+}
+
+#[test]
+fn test_keep_references() -> AnyhowResult<()> {
+  let payloads = [
+    "&amp;&AMP;&lt &lt;&fjlig;&NotEqualTilde;&#60;&#x3C;&#X3c;&#0000065;&#x110000;&#0;",
+    "& && &; &# &#; &#x; &#xg; &foo; &amp &ampx; &a#1; &#a;",
+    "<a href=\"?a=1&b=2\">\u{a9}\u{4e16}\u{1f600}</a>",
+    "&#x3c<&amp&lt;;&&lt;",
+    "\u{0}\u{80}\u{fffe}\r\n",
+  ];
+  let charsets = vec![
+    CharacterSet::Html,
+    CharacterSet::SpecialCharsAndNonAscii,
+    CharacterSet::NonAscii,
+    CharacterSet::All,
+    CharacterSet::Markdown,
+  ];
+  let encode_types = [
+    EncodeType::Named,
+    EncodeType::Hex,
+    EncodeType::NamedOrDecimal,
+    EncodeType::Shortest,
+  ];
+  for charset in charsets {
+    for encode_type in encode_types {
+      let options = EncodeOptions {
+        encode_type,
+        charset: charset.clone(),
+        keep_references: true,
+        ..Default::default()
+      };
+      for payload in payloads {
+        let once = encode_with_options(payload.as_bytes(), &options)?.to_bytes();
+        let twice = encode_with_options(&once, &options)?.to_bytes();
+        assert_eq!(once, twice, "{}", payload);
+        let mut data = vec![];
+        encode_with_options_to(&once, &options, &mut data)?;
+        assert_eq!(data, once);
+      }
+    }
+  }
+  let options = EncodeOptions {
+    charset: CharacterSet::Html,
+    keep_references: true,
+    ..Default::default()
+  };
+  let encode_to_string =
+    |content: &str| encode_with_options(content.as_bytes(), &options)?.to_string();
+  assert_eq!(
+    encode_to_string("&amp; &AMP; &#x3C; &fjlig; <b>")?,
+    "&amp; &AMP; &#x3C; &fjlig; &lt;b&gt;"
+  );
+  // the '&' not starting a character reference is encoded
+  assert_eq!(
+    encode_to_string("& &foo; &amp &#; &ampx; ?a=1&b=2")?,
+    "&amp; &amp;foo; &amp;amp &amp;#; &amp;ampx; ?a=1&amp;b=2"
+  );
+  // the characters in the kept references are not encoded
+  let options = EncodeOptions {
+    charset: CharacterSet::All,
+    encode_type: EncodeType::Decimal,
+    keep_references: true,
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_with_options(b"&lt;a", &options)?.to_string()?,
+    "&lt;&#97;"
+  );
+  // only the predefined entities and the references to the legal `Char` are kept in xml
+  let options = EncodeOptions {
+    charset: CharacterSet::Html,
+    xml_version: Some(XmlVersion::V1_0),
+    keep_references: true,
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_with_options(b"&copy; &nbsp;", &options)?.to_string()?,
+    "&amp;copy; &amp;nbsp;"
+  );
+  assert_eq!(
+    encode_with_options(b"&lt; &apos; &#x3c; &#60; &#X3C; &#1; &#xfffe;", &options)?.to_string()?,
+    "&lt; &apos; &#x3c; &#60; &amp;#X3C; &amp;#1; &amp;#xfffe;"
+  );
+  let options = EncodeOptions {
+    xml_version: Some(XmlVersion::V1_1),
+    ..options
+  };
+  assert_eq!(
+    encode_with_options(b"&#1; &#0;", &options)?.to_string()?,
+    "&#1; &amp;#0;"
+  );
+  // only the named references in the entity set are kept
+  let options = EncodeOptions {
+    charset: CharacterSet::Html,
+    entity_set: EntitySet::Html4,
+    keep_references: true,
+    ..Default::default()
+  };
+  assert_eq!(
+    encode_with_options(b"&copy; &apos; &bigstar; &#x3c;", &options)?.to_string()?,
+    "&copy; &amp;apos; &amp;bigstar; &#x3c;"
+  );
+  // the option is off by default
+  assert_eq!(
+    encode_with_options(b"&amp;", &Default::default())?.to_string()?,
+    "&amp;amp;"
+  );
+  Ok(())
+}
@@ -0,0 +1,136 @@
+use htmlentity::entity::{
+  decode_with_to, encode_to, CharacterSet, DecodeMode, DecodeOptions, EncodeType,
+};
+use htmlentity::io::{DecodingReader, DecodingWriter, EncodingReader, EncodingWriter};
+use std::io::{self, Read, Write};
+
+// a reader returns at most `size` bytes each read
+struct ChunkReader<'a> {
+  content: &'a [u8],
+  size: usize,
+}
+
+impl<'a> Read for ChunkReader<'a> {
+  fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+    let count = self.size.min(buf.len()).min(self.content.len());
+    buf[..count].copy_from_slice(&self.content[..count]);
+    self.content = &self.content[count..];
+    Ok(count)
+  }
+}
+
+const ENCODE_CONTENT: &str = "<p class='a'>\u{4e16}\u{754c} & \u{1f600}</p>";
+const DECODE_CONTENT: &str = "&lt;p&gt;&#x4e16;&#30028;&amp&notin;&notit;&#128512;&lt;/p&gt;&lt";
+
+#[test]
+fn test_encoding_writer() -> io::Result<()> {
+  let content = ENCODE_CONTENT.as_bytes();
+  let mut expected = vec![];
+  encode_to(
+    content,
+    &EncodeType::NamedOrHex,
+    &CharacterSet::HtmlAndNonAscii,
+    &mut expected,
+  );
+  for size in 1..=content.len() {
+    let mut writer = EncodingWriter::new(
+      vec![],
+      EncodeType::NamedOrHex,
+      CharacterSet::HtmlAndNonAscii,
+    );
+    for chunk in content.chunks(size) {
+      writer.write_all(chunk)?;
+      writer.flush()?;
+    }
+    assert_eq!(writer.finish()?, expected);
+  }
+  Ok(())
+}
+
+#[test]
+fn test_decoding_writer() -> io::Result<()> {
+  let content = DECODE_CONTENT.as_bytes();
+  for mode in [DecodeMode::Strict, DecodeMode::Html5] {
+    let options = DecodeOptions {
+      mode,
+      ..Default::default()
+    };
+    let mut expected = vec![];
+    decode_with_to(content, &options, &mut expected);
+    for size in 1..=content.len() {
+      let mut writer = DecodingWriter::new(vec![], options);
+      for chunk in content.chunks(size) {
+        writer.write_all(chunk)?;
+        writer.flush()?;
+      }
+      assert_eq!(writer.finish()?, expected);
+    }
+  }
+  // flush keeps the partial character reference
+  let mut writer = DecodingWriter::new(vec![], DecodeOptions::default());
+  writer.write_all(b"a&am")?;
+  writer.flush()?;
+  assert_eq!(writer.get_ref(), b"a");
+  writer.write_all(b"p;")?;
+  writer.flush()?;
+  assert_eq!(writer.get_ref(), b"a&");
+  // the pending bytes are written when the writer is dropped
+  let mut data = vec![];
+  {
+    let mut writer = DecodingWriter::new(&mut data, DecodeOptions::default());
+    writer.write_all(b"&lt;&lt")?;
+  }
+  assert_eq!(data, b"<&lt");
+  Ok(())
+}
+
+#[test]
+fn test_encoding_reader() -> io::Result<()> {
+  let content = ENCODE_CONTENT.as_bytes();
+  let mut expected = vec![];
+  encode_to(
+    content,
+    &EncodeType::Decimal,
+    &CharacterSet::All,
+    &mut expected,
+  );
+  for size in 1..=content.len() {
+    let mut reader = EncodingReader::new(
+      ChunkReader { content, size },
+      EncodeType::Decimal,
+      CharacterSet::All,
+    );
+    let mut data = vec![];
+    reader.read_to_end(&mut data)?;
+    assert_eq!(data, expected);
+  }
+  Ok(())
+}
+
+#[test]
+fn test_decoding_reader() -> io::Result<()> {
+  let content = DECODE_CONTENT.as_bytes();
+  for mode in [DecodeMode::Strict, DecodeMode::Html5] {
+    let options = DecodeOptions {
+      mode,
+      ..Default::default()
+    };
+    let mut expected = vec![];
+    decode_with_to(content, &options, &mut expected);
+    for size in 1..=content.len() {
+      let mut reader = DecodingReader::new(ChunkReader { content, size }, options);
+      let mut data = vec![];
+      // read with a small buffer
+      let mut buf = [0; 3];
+      loop {
+        let count = reader.read(&mut buf)?;
+        if count == 0 {
+          break;
+        }
+        data.extend_from_slice(&buf[..count]);
+      }
+      assert_eq!(data, expected);
+    }
+  }
+  Ok(())
+}
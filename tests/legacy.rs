@@ -0,0 +1,159 @@
+use htmlentity::charset::CharSet;
+use htmlentity::entity::{encode, encode_to, CharacterSet, EncodeType, ICodedDataTrait};
+use htmlentity::legacy::{LegacyEncoding, TranscodeError};
+use htmlentity::types::AnyhowResult;
+use std::collections::HashMap;
+
+#[test]
+fn test_legacy_representable() {
+  let cases = vec![
+    (LegacyEncoding::Windows1252, '\u{e9}', Some(vec![0xe9])),
+    (LegacyEncoding::Windows1252, '\u{20ac}', Some(vec![0x80])),
+    // the bytes undefined in the code page are mapped to the c1 control characters by the WHATWG index
+    (LegacyEncoding::Windows1252, '\u{81}', Some(vec![0x81])),
+    (LegacyEncoding::Windows1250, '\u{83}', Some(vec![0x83])),
+    (LegacyEncoding::Windows1251, '\u{98}', Some(vec![0x98])),
+    (LegacyEncoding::Windows1252, '\u{4e16}', None),
+    // the c1 control characters of ISO-8859-1 are decoded as windows-1252 by the browsers
+    (LegacyEncoding::Iso8859_1, '\u{e9}', Some(vec![0xe9])),
+    (LegacyEncoding::Iso8859_1, '\u{80}', None),
+    (LegacyEncoding::Iso8859_1, '\u{20ac}', None),
+    (LegacyEncoding::Iso8859_15, '\u{20ac}', Some(vec![0xa4])),
+    (LegacyEncoding::Iso8859_15, '\u{a4}', None),
+    (LegacyEncoding::Windows1250, '\u{159}', Some(vec![0xf8])),
+    (LegacyEncoding::Iso8859_2, '\u{159}', Some(vec![0xf8])),
+    (LegacyEncoding::Windows1251, '\u{416}', Some(vec![0xc6])),
+    (LegacyEncoding::Iso8859_5, '\u{416}', Some(vec![0xb6])),
+    (LegacyEncoding::Iso8859_7, '\u{3a9}', Some(vec![0xd9])),
+    (LegacyEncoding::Iso8859_7, '\u{e9}', None),
+    (LegacyEncoding::ShiftJis, '\u{3042}', Some(vec![0x82, 0xa0])),
+    (LegacyEncoding::ShiftJis, '\u{ff71}', Some(vec![0xb1])),
+    (LegacyEncoding::ShiftJis, '\u{a5}', Some(vec![0x5c])),
+    (LegacyEncoding::ShiftJis, '\u{80}', Some(vec![0x80])),
+    (LegacyEncoding::ShiftJis, '\u{2212}', Some(vec![0x81, 0x7c])),
+    (LegacyEncoding::ShiftJis, '\u{ff5e}', Some(vec![0x81, 0x60])),
+    // the IBM extensions are preferred to the NEC selected ones
+    (LegacyEncoding::ShiftJis, '\u{2170}', Some(vec![0xfa, 0x40])),
+    // not in the WHATWG index
+    (LegacyEncoding::ShiftJis, '\u{301c}', None),
+    (LegacyEncoding::ShiftJis, '\u{a2}', None),
+    (LegacyEncoding::ShiftJis, '\u{e9}', None),
+    (LegacyEncoding::ShiftJis, '\u{1f600}', None),
+  ];
+  for (encoding, ch, bytes) in cases {
+    let mut data = vec![];
+    let written = encoding.write_char(&ch, &mut data);
+    assert_eq!(encoding.is_representable(&ch), bytes.is_some());
+    assert_eq!(written, bytes.is_some());
+    assert_eq!(
+      data,
+      bytes.unwrap_or_default(),
+      "{} {:?}",
+      encoding.name(),
+      ch
+    );
+  }
+  // the ascii characters are always representable
+  for code in 0..0x80u8 {
+    let ch = char::from(code);
+    assert!(LegacyEncoding::ShiftJis.is_representable(&ch));
+    assert!(LegacyEncoding::Iso8859_7.is_representable(&ch));
+  }
+}
+
+#[test]
+fn test_legacy_round_trip() {
+  // the count of the high bytes defined in the WHATWG index of the single-byte encodings
+  let encodings = [
+    (LegacyEncoding::Windows1250, 128),
+    (LegacyEncoding::Windows1251, 128),
+    (LegacyEncoding::Windows1252, 128),
+    (LegacyEncoding::Iso8859_1, 96),
+    (LegacyEncoding::Iso8859_2, 128),
+    (LegacyEncoding::Iso8859_5, 128),
+    (LegacyEncoding::Iso8859_7, 125),
+    (LegacyEncoding::Iso8859_15, 128),
+  ];
+  for (encoding, count) in encodings {
+    let mut chars = HashMap::new();
+    for ch in encoding.representable_chars() {
+      let mut data = vec![];
+      assert!(encoding.write_char(&ch, &mut data));
+      assert_eq!(data.len(), 1);
+      // each byte is decoded back into one character
+      assert_eq!(
+        chars.insert(data[0], ch),
+        None,
+        "{} {:?}",
+        encoding.name(),
+        ch
+      );
+    }
+    assert_eq!(chars.len(), 128 + count, "{}", encoding.name());
+    assert!((0..0x80u8).all(|byte| chars[&byte] == char::from(byte)));
+  }
+  let encoding = LegacyEncoding::ShiftJis;
+  let mut chars = HashMap::new();
+  for ch in encoding.representable_chars() {
+    let mut data = vec![];
+    assert!(encoding.write_char(&ch, &mut data));
+    // the WHATWG encoder maps these characters to the bytes of other characters
+    if matches!(ch, '\u{a5}' | '\u{203e}' | '\u{2212}') {
+      continue;
+    }
+    assert_eq!(chars.insert(data, ch), None, "{:?}", ch);
+  }
+  assert_eq!(chars[&vec![0x81, 0x7c]], '\u{ff0d}');
+}
+
+#[test]
+fn test_encode_not_representable() -> AnyhowResult<()> {
+  let encoding = LegacyEncoding::ShiftJis;
+  let charset = CharacterSet::NotRepresentableIn(encoding);
+  assert!(charset.contains(&'\u{e9}') && !charset.contains(&'\u{4e16}'));
+  let content = "\u{4e16}\u{754c} caf\u{e9} \u{1f600}";
+  assert_eq!(
+    encode(content.as_bytes(), &EncodeType::NamedOrDecimal, &charset).to_string()?,
+    "\u{4e16}\u{754c} caf&eacute; &#128512;"
+  );
+  // the set agrees with the character set
+  let set = CharSet::from(&charset);
+  for code in (0..0x500).chain(0x3000..0x3100).chain(0xff00..0xffff) {
+    if let Some(ch) = char::from_u32(code) {
+      assert_eq!(set.contains(&ch), charset.contains(&ch));
+    }
+  }
+  // escape the html characters too, then transcode into the encoding
+  let charset = CharacterSet::Custom(set.union(&CharSet::from(&CharacterSet::Html)));
+  let mut encoded = vec![];
+  encode_to(
+    "<p>\u{3042}\u{e9}</p>".as_bytes(),
+    &EncodeType::NamedOrHex,
+    &charset,
+    &mut encoded,
+  );
+  let mut data = vec![];
+  encoding.transcode(&encoded, &mut data)?;
+  assert_eq!(data, b"&lt;p&gt;\x82\xa0&eacute;&lt;/p&gt;");
+  Ok(())
+}
+
+#[test]
+fn test_transcode_errors() {
+  let encoding = LegacyEncoding::Windows1252;
+  let mut data = b"head:".to_vec();
+  assert_eq!(
+    encoding.transcode(b"ok\xff", &mut data),
+    Err(TranscodeError::InvalidUtf8(2))
+  );
+  assert_eq!(
+    encoding.transcode("\u{20ac}\u{4e16}".as_bytes(), &mut data),
+    Err(TranscodeError::NotRepresentable(3, '\u{4e16}'))
+  );
+  // nothing is written on errors
+  assert_eq!(data, b"head:");
+  encoding
+    .transcode("\u{20ac}".as_bytes(), &mut data)
+    .unwrap();
+  assert_eq!(data, b"head:\x80");
+}
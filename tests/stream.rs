@@ -0,0 +1,240 @@
+use htmlentity::entity::{
+  decode_with_to, encode_char, encode_to, encode_with_to, CharacterSet, DecodeContext, DecodeMode,
+  DecodeOptions, EncodeType, EntityType, XmlVersion,
+};
+use htmlentity::stream::{StreamDecoder, StreamEncoder};
+use std::borrow::Cow;
+
+fn all_options() -> Vec<DecodeOptions> {
+  let mut options = vec![];
+  for mode in [
+    DecodeMode::Strict,
+    DecodeMode::Html5,
+    DecodeMode::Xml(XmlVersion::V1_0),
+  ] {
+    for context in [DecodeContext::Text, DecodeContext::Attribute] {
+      options.push(DecodeOptions {
+        mode,
+        context,
+        ..Default::default()
+      });
+    }
+  }
+  options
+}
+
+fn decode_in_chunks(content: &[u8], chunk_size: usize, options: DecodeOptions) -> Vec<u8> {
+  let mut decoder = StreamDecoder::new(options);
+  let mut data = vec![];
+  for chunk in content.chunks(chunk_size) {
+    decoder.feed(chunk, &mut data);
+  }
+  decoder.finish(&mut data);
+  data
+}
+
+fn assert_same_as_decode(content: &[u8]) {
+  for options in all_options() {
+    let mut expected = vec![];
+    decode_with_to(content, &options, &mut expected);
+    for chunk_size in 1..=content.len().max(1) {
+      assert_eq!(
+        decode_in_chunks(content, chunk_size, options),
+        expected,
+        "content: {:?}, chunk size: {}, options: {:?}",
+        String::from_utf8_lossy(content),
+        chunk_size,
+        options
+      );
+    }
+  }
+}
+
+#[test]
+fn test_stream_decode_chunks() {
+  let contents = [
+    "",
+    "&",
+    "&&",
+    "a&amp;b",
+    "&lt;div&gt;&amp&ampx&amp=&notin;&notit;&not",
+    "&#;&#x;&#60;&#x3c&#X3C;&#0;&#128;&#xD800;&#1114112;",
+    "&NotEqualTilde;&fjlig;&CounterClockwiseContourIntegral;&CounterClockwise",
+    "&foo;&foo&#60&#x3cz&amp;&",
+    "中&lt;文&#x4e16;&",
+  ];
+  for content in contents {
+    assert_same_as_decode(content.as_bytes());
+  }
+}
+
+#[test]
+fn test_stream_decode_long_numeric() {
+  let zeros = "0".repeat(200);
+  let contents = [
+    format!("a&#{}60;b", zeros),
+    format!("a&#x{}3c;b", zeros),
+    format!("a&#{}60b", zeros),
+    format!("a&#x{}b", zeros),
+    format!("a&#{}", zeros),
+    format!("a&#{}1{};b", zeros, zeros),
+    format!("a&#x{}fffffffffff;b", zeros),
+    format!("a&#{};b", "9".repeat(32)),
+    format!("a&#{};b", "9".repeat(33)),
+    format!("a&#x{}{}b", zeros, "f".repeat(100)),
+  ];
+  for content in contents {
+    for options in all_options() {
+      let mut expected = vec![];
+      decode_with_to(content.as_bytes(), &options, &mut expected);
+      for chunk_size in [1, 3, 7, 64, 65, 1000] {
+        assert_eq!(
+          decode_in_chunks(content.as_bytes(), chunk_size, options),
+          expected
+        );
+      }
+    }
+  }
+}
+
+#[test]
+fn test_stream_decode_pending() {
+  let mut decoder = StreamDecoder::new(DecodeOptions::default());
+  let mut data = vec![];
+  decoder.feed(b"...&am", &mut data);
+  assert_eq!(data, b"...");
+  assert!(decoder.has_pending());
+  decoder.feed(b"p;...", &mut data);
+  assert_eq!(data, b"...&...");
+  assert!(!decoder.has_pending());
+  // the pending bytes are bounded for the long numeric references
+  let html5 = DecodeOptions {
+    mode: DecodeMode::Html5,
+    ..Default::default()
+  };
+  let mut decoder = StreamDecoder::new(html5);
+  let mut data = vec![];
+  decoder.feed(b"&#", &mut data);
+  for _ in 0..10000 {
+    decoder.feed(b"1234567890", &mut data);
+  }
+  assert!(data.is_empty());
+  decoder.feed(b";", &mut data);
+  decoder.finish(&mut data);
+  assert_eq!(data, "\u{fffd}".as_bytes());
+  // the decoder can be reused after finished
+  decoder.feed(b"&lt", &mut data);
+  decoder.finish(&mut data);
+  assert_eq!(data, "\u{fffd}<".as_bytes());
+}
+
+#[test]
+fn test_stream_decode_pending_is_bounded() {
+  let xml = DecodeMode::Xml(XmlVersion::V1_0);
+  for mode in [DecodeMode::Strict, DecodeMode::Html5, xml] {
+    let options = DecodeOptions {
+      mode,
+      ..Default::default()
+    };
+    let mut decoder = StreamDecoder::new(options);
+    let mut data = vec![];
+    decoder.feed(b"&#", &mut data);
+    for _ in 0..1000 {
+      decoder.feed(b"1234567", &mut data);
+    }
+    // only the eight significant digits are kept in the html5 mode,
+    // the reference is written out as is once it can't be decoded in the other modes
+    match mode {
+      DecodeMode::Html5 => assert!(data.is_empty() && decoder.has_pending()),
+      _ => assert!(data.len() > 6000 && !decoder.has_pending()),
+    }
+    decoder.feed(b";a", &mut data);
+    decoder.finish(&mut data);
+    let content = format!("&#{};a", "1234567".repeat(1000));
+    let mut expected = vec![];
+    decode_with_to(content.as_bytes(), &options, &mut expected);
+    assert_eq!(data, expected);
+    let expected = match mode {
+      DecodeMode::Html5 => "\u{fffd}a",
+      _ => &content,
+    };
+    assert_eq!(data, expected.as_bytes());
+  }
+}
+
+fn encode_in_chunks(content: &[u8], chunk_size: usize, mut encoder: StreamEncoder) -> Vec<u8> {
+  let mut data = vec![];
+  for chunk in content.chunks(chunk_size) {
+    encoder.feed(chunk, &mut data);
+  }
+  encoder.finish(&mut data);
+  data
+}
+
+#[test]
+fn test_stream_encode_chunks() {
+  let contents: [&[u8]; 7] = [
+    b"",
+    "<p>\u{4e16}\u{754c}&\u{1f600}</p>".as_bytes(),
+    b"\xe4\xb8<\xe4\xb8\x96",
+    b"\xf0\x9f\x98<\x80\x80\xff>",
+    b"\xed\xa0\x80'\xc3",
+    b"a\xf0\x9f",
+    b"\xc3\xa9\xe4\xb8",
+  ];
+  let encode_settings = || {
+    vec![
+      (EncodeType::Named, CharacterSet::SpecialChars),
+      (EncodeType::NamedOrHex, CharacterSet::HtmlAndNonAscii),
+      (EncodeType::Decimal, CharacterSet::All),
+      (EncodeType::Shortest, CharacterSet::All),
+    ]
+  };
+  for content in contents {
+    for (index, (encode_type, charset)) in encode_settings().into_iter().enumerate() {
+      let mut expected = vec![];
+      encode_to(content, &encode_type, &charset, &mut expected);
+      for chunk_size in 1..=content.len().max(1) {
+        let (encode_type, charset) = encode_settings().into_iter().nth(index).unwrap();
+        assert_eq!(
+          encode_in_chunks(
+            content,
+            chunk_size,
+            StreamEncoder::new(encode_type, charset)
+          ),
+          expected,
+          "content: {:?}, chunk size: {}",
+          content,
+          chunk_size
+        );
+      }
+    }
+  }
+}
+
+#[test]
+fn test_stream_encode_with_filter() {
+  let filter = |ch: &char, _: &EncodeType| {
+    if *ch == '\u{4e16}' {
+      let entity = encode_char(ch, &EncodeType::Decimal).unwrap();
+      return (true, Some((EntityType::Decimal, Cow::from(entity.data()))));
+    }
+    (false, None)
+  };
+  let content = "\u{4e16}\u{754c}\u{4e16}".as_bytes();
+  let mut expected = vec![];
+  encode_with_to(content, &EncodeType::Named, filter, &mut expected);
+  assert_eq!(expected, "&#19990;\u{754c}&#19990;".as_bytes());
+  for chunk_size in 1..=content.len() {
+    let encoder = StreamEncoder::with_filter(EncodeType::Named, filter);
+    assert_eq!(encode_in_chunks(content, chunk_size, encoder), expected);
+  }
+  let mut encoder = StreamEncoder::with_filter(EncodeType::Named, filter);
+  let mut data = vec![];
+  encoder.feed(&content[..2], &mut data);
+  assert!(data.is_empty());
+  assert!(encoder.has_pending());
+  encoder.feed(&content[2..3], &mut data);
+  assert_eq!(data, b"&#19990;");
+  assert!(!encoder.has_pending());
+}
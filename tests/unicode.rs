@@ -0,0 +1,127 @@
+#![cfg(feature = "unicode-properties")]
+use htmlentity::charset::CharSet;
+use htmlentity::entity::{encode, encode_to, CharacterSet, EncodeType, ICodedDataTrait};
+use htmlentity::types::AnyhowResult;
+use htmlentity::unicode::{UnicodeProperty, UnicodePropertyError, UnicodePropertyKind};
+
+#[test]
+fn test_unicode_property_lookup() {
+  let names = [
+    ("Cf", UnicodePropertyKind::GeneralCategory, "Format"),
+    ("format", UnicodePropertyKind::GeneralCategory, "Format"),
+    ("gc=Cn", UnicodePropertyKind::GeneralCategory, "Unassigned"),
+    ("L", UnicodePropertyKind::GeneralCategory, "Letter"),
+    (
+      "General_Category=Lu",
+      UnicodePropertyKind::GeneralCategory,
+      "Uppercase_Letter",
+    ),
+    ("Han", UnicodePropertyKind::Script, "Han"),
+    ("sc=hani", UnicodePropertyKind::Script, "Han"),
+    ("Script = Latin", UnicodePropertyKind::Script, "Latin"),
+    ("EPres", UnicodePropertyKind::Binary, "Emoji_Presentation"),
+    (
+      "emoji-presentation",
+      UnicodePropertyKind::Binary,
+      "Emoji_Presentation",
+    ),
+    (
+      "Extended_Pictographic",
+      UnicodePropertyKind::Binary,
+      "Extended_Pictographic",
+    ),
+  ];
+  for (name, kind, canonical) in names {
+    let property = UnicodeProperty::parse(name).unwrap();
+    assert_eq!(
+      (property.kind(), property.name()),
+      (kind, canonical),
+      "{}",
+      name
+    );
+  }
+  assert_eq!(
+    UnicodeProperty::parse("Age=1.1"),
+    Err(UnicodePropertyError::UnknownName)
+  );
+  assert_eq!(
+    UnicodeProperty::parse("sc=Cf"),
+    Err(UnicodePropertyError::UnknownValue)
+  );
+  // the binary properties not bundled
+  assert_eq!(
+    UnicodeProperty::parse("Alphabetic"),
+    Err(UnicodePropertyError::UnknownValue)
+  );
+  assert!(UnicodeProperty::script("Cf").is_none());
+  assert!(UnicodeProperty::binary("Han").is_none());
+}
+
+#[test]
+fn test_unicode_property_contains() {
+  let contains = |name: &str, ch: char| UnicodeProperty::parse(name).unwrap().contains(&ch);
+  assert!(contains("Cf", '\u{ad}') && contains("Cf", '\u{feff}') && contains("Cf", '\u{e007f}'));
+  assert!(!contains("Cf", 'a') && !contains("Cf", '\u{200a}'));
+  assert!(contains("Cn", '\u{378}') && contains("Cn", '\u{10ffff}') && !contains("Cn", 'a'));
+  assert!(!contains("Cs", '\u{d7ff}'));
+  assert!(contains("Han", '\u{4e16}') && contains("Han", '\u{3007}') && !contains("Han", 'a'));
+  assert!(contains("Emoji_Presentation", '\u{1f600}'));
+  assert!(!contains("Emoji_Presentation", '\u{a9}') && contains("Emoji", '\u{a9}'));
+  assert!(contains("White_Space", '\u{3000}') && !contains("White_Space", '\u{200b}'));
+  // the group categories are the union of the categories
+  let letter = UnicodeProperty::parse("L").unwrap();
+  let other = UnicodeProperty::parse("C").unwrap();
+  let leaves = ["Lu", "Ll", "Lt", "Lm", "Lo"];
+  let leaves = leaves
+    .iter()
+    .map(|name| UnicodeProperty::parse(name).unwrap())
+    .collect::<Vec<_>>();
+  for code in 0..0x3000 {
+    if let Some(ch) = char::from_u32(code) {
+      assert_eq!(
+        letter.contains(&ch),
+        leaves.iter().any(|leaf| leaf.contains(&ch))
+      );
+      // every character has only one general category
+      assert!(!(letter.contains(&ch) && other.contains(&ch)));
+    }
+  }
+  // convert to the char set
+  let set = CharSet::from(&letter);
+  assert!(set.contains(&'a') && set.contains(&'\u{4e16}') && !set.contains(&'1'));
+}
+
+#[test]
+fn test_encode_unicode_property() -> AnyhowResult<()> {
+  let charset = CharacterSet::UnicodeProperty(UnicodeProperty::parse("Emoji_Presentation")?);
+  assert!(charset.contains(&'\u{1f600}') && !charset.contains(&'a'));
+  let content = "ok \u{1f600}\u{2705} \u{a9}";
+  assert_eq!(
+    encode(content.as_bytes(), &EncodeType::Hex, &charset).to_string()?,
+    "ok &#x1f600;&#x2705; \u{a9}"
+  );
+  let charset = CharacterSet::UnicodeProperty(UnicodeProperty::parse("Cf")?);
+  let mut data = vec![];
+  encode_to(
+    "a\u{200b}b\u{202e}c".as_bytes(),
+    &EncodeType::NamedOrDecimal,
+    &charset,
+    &mut data,
+  );
+  assert_eq!(data, b"a&ZeroWidthSpace;b&#8238;c");
+  // the set algebra with the presets
+  let han = CharSet::from(&CharacterSet::UnicodeProperty(UnicodeProperty::parse(
+    "Han",
+  )?));
+  let charset = CharacterSet::Custom(han.union(&CharSet::from(&CharacterSet::Html)));
+  assert_eq!(
+    encode(
+      "<\u{4e16}\u{3042}>".as_bytes(),
+      &EncodeType::NamedOrHex,
+      &charset
+    )
+    .to_string()?,
+    "&lt;&#x4e16;\u{3042}&gt;"
+  );
+  Ok(())
+}